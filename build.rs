@@ -1,7 +1,213 @@
+/// A deployment target, with the URLs it uses unless `API_URL`/`SPS_URL`
+/// are set explicitly
+struct Profile {
+    name: &'static str,
+    api_url: Option<&'static str>,
+    sps_url: Option<&'static str>,
+    /// Production profiles can't ship a release build pointing at localhost
+    production: bool,
+}
+
+/// Selected with `DEPLOY_PROFILE`, `local` when unset
+const PROFILES: &[Profile] = &[
+    Profile {
+        name: "local",
+        api_url: Some("http://localhost:3000"),
+        sps_url: Some("http://localhost:5173"),
+        production: false,
+    },
+    // Behind the companion server or a reverse proxy on the same origin
+    Profile {
+        name: "docker",
+        api_url: Some("/api"),
+        sps_url: Some("/play"),
+        production: true,
+    },
+    // The production backend lives elsewhere, its URLs must be set
+    Profile {
+        name: "netlify",
+        api_url: None,
+        sps_url: None,
+        production: true,
+    },
+];
+
 fn main() {
-    let api_url = std::env::var("API_URL").unwrap_or_else(|_| "http://localhost:3000".to_string());
+    if let Err(error) = configure() {
+        eprintln!("error: {}", error);
+        std::process::exit(1);
+    }
+}
+
+fn configure() -> Result<(), String> {
+    for var in ["DEPLOY_PROFILE", "API_URL", "SPS_URL", "SOURCE_DATE_EPOCH"] {
+        println!("cargo:rerun-if-env-changed={}", var);
+    }
+    for path in ["build.rs", ".git/HEAD", ".git/refs"] {
+        println!("cargo:rerun-if-changed={}", path);
+    }
+
+    let name = std::env::var("DEPLOY_PROFILE").unwrap_or_else(|_| "local".to_string());
+    let profile = PROFILES
+        .iter()
+        .find(|profile| profile.name == name.trim())
+        .ok_or_else(|| {
+            let names: Vec<_> = PROFILES.iter().map(|profile| profile.name).collect();
+            format!(
+                "unknown DEPLOY_PROFILE '{}', expected one of: {}",
+                name,
+                names.join(", ")
+            )
+        })?;
+    let release = std::env::var("PROFILE").as_deref() == Ok("release");
+
+    let api_url = setting("API_URL", profile.api_url, profile.name)?;
+    for url in api_url
+        .split(',')
+        .map(str::trim)
+        .filter(|url| !url.is_empty())
+    {
+        check_url("API_URL", url, profile, release)?;
+    }
+    if api_url.split(',').all(|url| url.trim().is_empty()) {
+        return Err("API_URL lists no backend".to_string());
+    }
     println!("cargo:rustc-env=API_URL={}", api_url);
-    
-    let sps_url = std::env::var("SPS_URL").unwrap_or_else(|_| "http://localhost:5173".to_string());
-    println!("cargo:rustc-env=SPS_URL={}", sps_url);
+
+    let sps_url = setting("SPS_URL", profile.sps_url, profile.name)?;
+    check_url("SPS_URL", sps_url.trim(), profile, release)?;
+    println!("cargo:rustc-env=SPS_URL={}", sps_url.trim());
+
+    println!("cargo:rustc-env=GIT_COMMIT={}", git_commit());
+    println!("cargo:rustc-env=BUILD_DATE={}", build_date());
+
+    Ok(())
+}
+
+/// `var` from the environment, or the profile's default for it
+fn setting(var: &str, default: Option<&str>, profile: &str) -> Result<String, String> {
+    match std::env::var(var) {
+        Ok(value) if !value.trim().is_empty() => Ok(value),
+        _ => default
+            .map(str::to_string)
+            .ok_or_else(|| format!("{} must be set for the '{}' deploy profile", var, profile)),
+    }
+}
+
+/// Accepts absolute `http(s)://host[:port][/path]` URLs and paths on the
+/// page's own origin such as `/api`
+fn check_url(var: &str, url: &str, profile: &Profile, release: bool) -> Result<(), String> {
+    let invalid = |reason: &str| format!("{} entry '{}' is not a valid URL: {}", var, url, reason);
+
+    if url.chars().any(|c| c.is_whitespace() || c.is_control()) {
+        return Err(invalid("it contains whitespace"));
+    }
+    if url.contains(['?', '#']) {
+        return Err(invalid("query strings and fragments aren't supported"));
+    }
+    if url.starts_with("//") {
+        return Err(invalid("add an http:// or https:// scheme"));
+    }
+    if url.starts_with('/') {
+        return Ok(());
+    }
+
+    let rest = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+        .ok_or_else(|| invalid("expected http://, https:// or a path starting with /"))?;
+    let authority = rest.split('/').next().unwrap_or_default();
+    let (host, port) = match authority.strip_prefix('[') {
+        Some(ipv6) => match ipv6.split_once(']') {
+            Some((_, "")) => (authority, None),
+            Some((_, rest)) if rest.starts_with(':') => {
+                (&authority[..authority.len() - rest.len()], Some(&rest[1..]))
+            }
+            _ => return Err(invalid("the IPv6 host is malformed")),
+        },
+        None => match authority.split_once(':') {
+            Some((host, port)) => (host, Some(port)),
+            None => (authority, None),
+        },
+    };
+
+    if host.is_empty() {
+        return Err(invalid("it has no host"));
+    }
+    let valid_host = if let Some(ipv6) = host.strip_prefix('[') {
+        ipv6.strip_suffix(']')
+            .is_some_and(|ip| ip.parse::<std::net::Ipv6Addr>().is_ok())
+    } else {
+        host.split('.').all(|label| {
+            !label.is_empty()
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        })
+    };
+    if !valid_host {
+        return Err(invalid("the host name is malformed"));
+    }
+    if port.is_some_and(|port| port.parse::<u16>().is_err()) {
+        return Err(invalid("the port is not a number between 0 and 65535"));
+    }
+
+    if release && profile.production && is_local(host) {
+        return Err(format!(
+            "{} points at {} in a release build for the '{}' deploy profile",
+            var, url, profile.name
+        ));
+    }
+
+    Ok(())
+}
+
+fn is_local(host: &str) -> bool {
+    let host = host.to_ascii_lowercase();
+    host == "localhost"
+        || host.ends_with(".localhost")
+        || host == "0.0.0.0"
+        || host == "[::1]"
+        || host.starts_with("127.")
+}
+
+/// Short hash of the commit being built, `unknown` outside a git checkout
+fn git_commit() -> String {
+    std::process::Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|hash| hash.trim().to_string())
+        .filter(|hash| !hash.is_empty())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+/// Today's date as `YYYY-MM-DD` (UTC), or the one in `SOURCE_DATE_EPOCH`
+/// for reproducible builds
+fn build_date() -> String {
+    let secs = std::env::var("SOURCE_DATE_EPOCH")
+        .ok()
+        .and_then(|epoch| epoch.parse::<i64>().ok())
+        .unwrap_or_else(|| {
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|elapsed| elapsed.as_secs() as i64)
+                .unwrap_or_default()
+        });
+
+    // Days since the epoch to a civil date, see
+    // https://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let z = secs.div_euclid(86_400) + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!("{:04}-{:02}-{:02}", year, month, day)
 }
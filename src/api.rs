@@ -0,0 +1,1049 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::model::*;
+use crate::openapi::ApiSpec;
+
+/// Documentation UIs served by the backend through utoipa
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DocsTool {
+    SwaggerUi,
+    Scalar,
+    Redoc,
+    RapiDoc,
+}
+
+impl DocsTool {
+    pub fn name(&self) -> &'static str {
+        match self {
+            DocsTool::SwaggerUi => "SwaggerUI",
+            DocsTool::Scalar => "Scalar",
+            DocsTool::Redoc => "Redoc",
+            DocsTool::RapiDoc => "RapiDoc",
+        }
+    }
+
+    pub fn path(&self) -> &'static str {
+        match self {
+            DocsTool::SwaggerUi => "/swagger-ui",
+            DocsTool::Scalar => "/scalar",
+            DocsTool::Redoc => "/redoc",
+            DocsTool::RapiDoc => "/rapidoc",
+        }
+    }
+
+    pub fn all() -> &'static [DocsTool] {
+        &[
+            DocsTool::SwaggerUi,
+            DocsTool::Scalar,
+            DocsTool::Redoc,
+            DocsTool::RapiDoc,
+        ]
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HealthCheck {
+    Alive,
+    Ready,
+}
+
+/// Every backend endpoint the frontend knows about.
+///
+/// Build URLs through this type rather than by hand, so path segments are
+/// always percent-encoded and the endpoint list lives in one place.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ApiRoute {
+    Random {
+        lang: LanguageCode,
+        word_type: GrammaticalType,
+    },
+    Lookup {
+        lang: LanguageCode,
+        word: String,
+    },
+    Health(HealthCheck),
+    OpenApiSpec,
+    Docs(DocsTool),
+}
+
+impl ApiRoute {
+    pub fn path(&self) -> String {
+        match self {
+            ApiRoute::Random { lang, word_type } => {
+                format!(
+                    "/{}/{}",
+                    encode_segment(lang.code()),
+                    encode_segment(word_type.api_name())
+                )
+            }
+            ApiRoute::Lookup { lang, word } => {
+                format!(
+                    "/{}/word/{}",
+                    encode_segment(lang.code()),
+                    encode_segment(word)
+                )
+            }
+            ApiRoute::Health(HealthCheck::Alive) => "/health/alive".to_string(),
+            ApiRoute::Health(HealthCheck::Ready) => "/health/ready".to_string(),
+            ApiRoute::OpenApiSpec => "/api-docs/openapi.json".to_string(),
+            ApiRoute::Docs(tool) => tool.path().to_string(),
+        }
+    }
+
+    pub fn url(&self, base: &str) -> String {
+        format!("{}{}", base.trim_end_matches('/'), self.path())
+    }
+
+    /// Like `url()`, with percent-encoded query parameters appended.
+    /// Pairs with an empty key are skipped.
+    pub fn url_with_query(&self, base: &str, query: &[(String, String)]) -> String {
+        let query: Vec<_> = query
+            .iter()
+            .filter(|(key, _)| !key.trim().is_empty())
+            .map(|(key, value)| format!("{}={}", encode_segment(key.trim()), encode_segment(value)))
+            .collect();
+
+        if query.is_empty() {
+            self.url(base)
+        } else {
+            format!("{}?{}", self.url(base), query.join("&"))
+        }
+    }
+}
+
+/// The endpoints a playground request can target, docs UIs aside since they're HTML
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Endpoint {
+    Random,
+    Lookup,
+    HealthAlive,
+    HealthReady,
+    OpenApiSpec,
+}
+
+impl Endpoint {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Endpoint::Random => "Random word",
+            Endpoint::Lookup => "Word lookup",
+            Endpoint::HealthAlive => "Health: alive",
+            Endpoint::HealthReady => "Health: ready",
+            Endpoint::OpenApiSpec => "OpenAPI spec",
+        }
+    }
+
+    pub fn all() -> &'static [Endpoint] {
+        &[
+            Endpoint::Random,
+            Endpoint::Lookup,
+            Endpoint::HealthAlive,
+            Endpoint::HealthReady,
+            Endpoint::OpenApiSpec,
+        ]
+    }
+}
+
+/// A request built in the playground, saved to the browser's local storage
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlaygroundRequest {
+    pub name: String,
+    pub endpoint: Endpoint,
+    pub lang: LanguageCode,
+    pub word_type: GrammaticalType,
+    #[serde(default)]
+    pub word: String,
+    #[serde(default)]
+    pub query: Vec<(String, String)>,
+    #[serde(default)]
+    pub headers: Vec<(String, String)>,
+}
+
+impl Default for PlaygroundRequest {
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            endpoint: Endpoint::Random,
+            lang: LanguageCode::English,
+            word_type: GrammaticalType::Random,
+            word: String::new(),
+            query: Vec::new(),
+            headers: Vec::new(),
+        }
+    }
+}
+
+impl PlaygroundRequest {
+    pub fn route(&self) -> ApiRoute {
+        match self.endpoint {
+            Endpoint::Random => ApiRoute::Random {
+                lang: self.lang,
+                word_type: self.word_type,
+            },
+            Endpoint::Lookup => ApiRoute::Lookup {
+                lang: self.lang,
+                word: self.word.clone(),
+            },
+            Endpoint::HealthAlive => ApiRoute::Health(HealthCheck::Alive),
+            Endpoint::HealthReady => ApiRoute::Health(HealthCheck::Ready),
+            Endpoint::OpenApiSpec => ApiRoute::OpenApiSpec,
+        }
+    }
+
+    pub fn url(&self, base: &str) -> String {
+        self.route().url_with_query(base, &self.query)
+    }
+}
+
+/// Percent-encode everything but RFC 3986 unreserved characters
+pub fn encode_segment(segment: &str) -> String {
+    let mut encoded = String::with_capacity(segment.len());
+    for byte in segment.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ApiError {
+    /// The request never got a response
+    Network(String),
+    /// The backend answered with a non-success status code
+    Status(u16),
+    /// The backend answered 429, retry once the limit resets
+    RateLimited { retry_after_secs: u32 },
+    /// The body isn't JSON at all
+    InvalidJson(String),
+    /// The body is JSON but doesn't match `Word`, usually frontend/backend version skew
+    SchemaMismatch(String),
+    /// The backend returned an empty list
+    NoWords,
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ApiError::Network(e) => write!(f, "Request failed: {}", e),
+            ApiError::Status(code) => write!(f, "Backend responded with status {}", code),
+            ApiError::RateLimited { retry_after_secs } => {
+                write!(f, "Rate limited, retry in {}s", retry_after_secs)
+            }
+            ApiError::InvalidJson(e) => write!(f, "Failed to parse JSON: {}", e),
+            ApiError::SchemaMismatch(e) => write!(
+                f,
+                "Response doesn't match the expected word schema, frontend and backend versions may differ: {}",
+                e
+            ),
+            ApiError::NoWords => write!(f, "No words found in response"),
+        }
+    }
+}
+
+impl std::error::Error for ApiError {}
+
+impl ApiError {
+    /// Whether another backend might succeed where this one failed
+    pub fn is_backend_failure(&self) -> bool {
+        match self {
+            ApiError::Network(_) => true,
+            ApiError::Status(code) => *code >= 500,
+            _ => false,
+        }
+    }
+}
+
+/// Consecutive backend failures before the circuit opens
+pub const FAILURE_THRESHOLD: u32 = 3;
+/// Seconds the circuit stays open before a half-open probe is sent
+pub const COOLDOWN_SECS: u32 = 30;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CircuitState {
+    Closed {
+        failures: u32,
+    },
+    Open {
+        remaining_secs: u32,
+    },
+    /// Waiting for a probe, or for the one in flight when `probing`
+    HalfOpen {
+        probing: bool,
+    },
+}
+
+/// Stops firing requests at a backend that keeps failing, e.g. when the
+/// free tier is out of quota.
+///
+/// After `FAILURE_THRESHOLD` consecutive failures the circuit opens for
+/// `COOLDOWN_SECS`, counted down by `tick()`. It then goes half-open and lets
+/// a single probe through, started with `start_request()`, and holds back
+/// every other request until it completes: success closes the circuit,
+/// failure opens it again.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CircuitBreaker {
+    state: CircuitState,
+}
+
+impl Default for CircuitBreaker {
+    fn default() -> Self {
+        Self {
+            state: CircuitState::Closed { failures: 0 },
+        }
+    }
+}
+
+impl CircuitBreaker {
+    pub fn state(&self) -> CircuitState {
+        self.state
+    }
+
+    pub fn allows_request(&self) -> bool {
+        match self.state {
+            CircuitState::Closed { .. } => true,
+            CircuitState::Open { .. } => false,
+            CircuitState::HalfOpen { probing } => !probing,
+        }
+    }
+
+    /// Call before sending a request, returns whether it may go. When
+    /// half-open the request becomes the probe, and the next ones wait for
+    /// `record_success()` or `record_failure()`.
+    pub fn start_request(&mut self) -> bool {
+        if !self.allows_request() {
+            return false;
+        }
+        if let CircuitState::HalfOpen { .. } = self.state {
+            self.state = CircuitState::HalfOpen { probing: true };
+        }
+        true
+    }
+
+    /// The backend answered, with a word or an error that isn't its failure
+    pub fn record_success(&mut self) {
+        self.state = CircuitState::Closed { failures: 0 };
+    }
+
+    pub fn record_failure(&mut self) {
+        self.state = match self.state {
+            CircuitState::Closed { failures } if failures + 1 < FAILURE_THRESHOLD => {
+                CircuitState::Closed {
+                    failures: failures + 1,
+                }
+            }
+            CircuitState::Open { remaining_secs } => CircuitState::Open { remaining_secs },
+            _ => CircuitState::Open {
+                remaining_secs: COOLDOWN_SECS,
+            },
+        };
+    }
+
+    /// Advance the countdown by one second, returns true when the circuit just went half-open
+    pub fn tick(&mut self) -> bool {
+        match self.state {
+            CircuitState::Open { remaining_secs } if remaining_secs > 1 => {
+                self.state = CircuitState::Open {
+                    remaining_secs: remaining_secs - 1,
+                };
+                false
+            }
+            CircuitState::Open { .. } => {
+                self.state = CircuitState::HalfOpen { probing: false };
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Fallback wait when a 429 comes without a usable reset header
+pub const DEFAULT_RETRY_AFTER_SECS: u32 = 60;
+
+/// Seconds to wait after a 429, from `Retry-After` or `RateLimit-Reset`.
+///
+/// Only the delta-seconds form is understood, an HTTP-date falls back to
+/// `DEFAULT_RETRY_AFTER_SECS`.
+fn retry_after_secs(headers: &reqwest::header::HeaderMap) -> u32 {
+    [reqwest::header::RETRY_AFTER.as_str(), "ratelimit-reset"]
+        .iter()
+        .filter_map(|name| headers.get(*name)?.to_str().ok()?.trim().parse().ok())
+        .next()
+        .unwrap_or(DEFAULT_RETRY_AFTER_SECS)
+}
+
+/// What the request inspector shows for a single HTTP call
+#[derive(Debug, Clone, PartialEq)]
+pub struct RequestLog {
+    pub method: &'static str,
+    pub url: String,
+    pub status: Option<u16>,
+    pub headers: Vec<(String, String)>,
+    /// Only visible when the backend exposes it through CORS, the browser
+    /// decodes brotli/gzip bodies before they reach us
+    pub content_encoding: Option<String>,
+    /// Size of the decoded body in bytes
+    pub payload_bytes: usize,
+    pub elapsed_ms: f64,
+    /// The body, pretty-printed when it's JSON
+    pub body: String,
+}
+
+impl RequestLog {
+    fn new(method: &'static str, url: &str) -> Self {
+        Self {
+            method,
+            url: url.to_string(),
+            status: None,
+            headers: Vec::new(),
+            content_encoding: None,
+            payload_bytes: 0,
+            elapsed_ms: 0.0,
+            body: String::new(),
+        }
+    }
+}
+
+fn record_response(log: &mut RequestLog, response: &reqwest::Response) {
+    log.status = Some(response.status().as_u16());
+    log.headers = response
+        .headers()
+        .iter()
+        .map(|(name, value)| {
+            let value = value.to_str().unwrap_or("<binary>");
+            (name.to_string(), value.to_string())
+        })
+        .collect();
+    log.content_encoding = response
+        .headers()
+        .get(reqwest::header::CONTENT_ENCODING)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string);
+}
+
+fn record_body(log: &mut RequestLog, body: &str) {
+    log.payload_bytes = body.len();
+    log.body = serde_json::from_str::<serde_json::Value>(body)
+        .ok()
+        .and_then(|json| serde_json::to_string_pretty(&json).ok())
+        .unwrap_or_else(|| body.to_string());
+}
+
+async fn request_word(url: &str, log: &mut RequestLog) -> Result<Word, ApiError> {
+    let response = reqwest::get(url)
+        .await
+        .map_err(|e| ApiError::Network(e.to_string()))?;
+    record_response(log, &response);
+
+    if response.status() == reqwest::StatusCode::TOO_MANY_REQUESTS {
+        return Err(ApiError::RateLimited {
+            retry_after_secs: retry_after_secs(response.headers()),
+        });
+    }
+
+    if !response.status().is_success() {
+        return Err(ApiError::Status(response.status().as_u16()));
+    }
+
+    let body = response
+        .text()
+        .await
+        .map_err(|e| ApiError::Network(e.to_string()))?;
+    record_body(log, &body);
+
+    // Parse in two steps so malformed JSON and a schema mismatch are told apart
+    let json = serde_json::from_str::<serde_json::Value>(&body)
+        .map_err(|e| ApiError::InvalidJson(e.to_string()))?;
+    let words = serde_json::from_value::<Vec<Word>>(json)
+        .map_err(|e| ApiError::SchemaMismatch(e.to_string()))?;
+
+    words.into_iter().next().ok_or(ApiError::NoWords)
+}
+
+/// Milliseconds since the Unix epoch, for timing requests. `js_sys` panics
+/// outside the browser, so native builds such as tests use the system clock.
+fn now_ms() -> f64 {
+    #[cfg(target_arch = "wasm32")]
+    let now = js_sys::Date::now();
+    #[cfg(not(target_arch = "wasm32"))]
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0.0, |elapsed| elapsed.as_secs_f64() * 1000.0);
+    now
+}
+
+async fn fetch_word(
+    base: &str,
+    lang: LanguageCode,
+    word_type: GrammaticalType,
+    on_request: &impl Fn(RequestLog),
+) -> Result<Word, ApiError> {
+    let url = ApiRoute::Random { lang, word_type }.url(base);
+    let mut log = RequestLog::new("GET", &url);
+
+    let started = now_ms();
+    let result = request_word(&url, &mut log).await;
+    log.elapsed_ms = now_ms() - started;
+
+    on_request(log);
+    result
+}
+
+/// Send an arbitrary GET request, as built in the playground.
+///
+/// Transport errors end up in the log body, since the playground shows the
+/// log rather than a word.
+pub async fn send_request(url: &str, headers: &[(String, String)]) -> RequestLog {
+    let mut log = RequestLog::new("GET", url);
+    let started = now_ms();
+
+    let request = headers
+        .iter()
+        .filter(|(name, _)| !name.trim().is_empty())
+        .fold(reqwest::Client::new().get(url), |request, (name, value)| {
+            request.header(name.trim(), value)
+        });
+
+    match request.send().await {
+        Ok(response) => {
+            record_response(&mut log, &response);
+            match response.text().await {
+                Ok(body) => record_body(&mut log, &body),
+                Err(e) => log.body = e.to_string(),
+            }
+        }
+        Err(e) => log.body = e.to_string(),
+    }
+
+    log.elapsed_ms = now_ms() - started;
+    log
+}
+
+async fn fetch_json(url: &str) -> Result<serde_json::Value, ApiError> {
+    let response = reqwest::get(url)
+        .await
+        .map_err(|e| ApiError::Network(e.to_string()))?;
+
+    if !response.status().is_success() {
+        return Err(ApiError::Status(response.status().as_u16()));
+    }
+
+    response
+        .json()
+        .await
+        .map_err(|e| ApiError::InvalidJson(e.to_string()))
+}
+
+/// Fetch the OpenAPI document from the first backend that serves it.
+/// Returns the index of that backend along with the parsed spec.
+pub async fn get_openapi_spec(backends: &[String]) -> Result<(usize, ApiSpec), ApiError> {
+    let mut last_error = ApiError::Network("No backend configured".to_string());
+
+    for (index, base) in backends.iter().enumerate() {
+        match fetch_json(&ApiRoute::OpenApiSpec.url(base)).await {
+            Ok(json) => return Ok((index, ApiSpec::from_json(&json))),
+            Err(e) => last_error = e,
+        }
+    }
+
+    Err(last_error)
+}
+
+/// The backend API version this frontend is written against. Backends are
+/// compatible when they share its major version, or its minor one before 1.0.
+pub const SUPPORTED_API_VERSION: &str = "0.1.0";
+
+/// Header the backend may announce its API version with
+pub const API_VERSION_HEADER: &str = "x-api-version";
+
+/// Whether a backend announcing `version` speaks the API this frontend
+/// expects, following Cargo's semver rules
+pub fn is_compatible_api_version(version: &str) -> bool {
+    fn major_minor(version: &str) -> Option<(u64, u64)> {
+        let mut parts = version.trim().trim_start_matches('v').split('.');
+        let major = parts.next()?.parse().ok()?;
+        let minor = parts.next().map_or(Some(0), |minor| minor.parse().ok())?;
+        Some((major, minor))
+    }
+
+    match (major_minor(version), major_minor(SUPPORTED_API_VERSION)) {
+        (Some((0, minor)), Some((0, supported_minor))) => minor == supported_minor,
+        (Some((major, _)), Some((supported_major, _))) => major == supported_major,
+        _ => false,
+    }
+}
+
+/// The API version of the first backend that announces one, from the
+/// `x-api-version` header of its health check or else its OpenAPI document
+pub async fn get_api_version(backends: &[String]) -> Option<(usize, String)> {
+    for (index, base) in backends.iter().enumerate() {
+        let url = ApiRoute::Health(HealthCheck::Alive).url(base);
+        if let Ok(response) = reqwest::get(&url).await {
+            let header = response
+                .headers()
+                .get(API_VERSION_HEADER)
+                .and_then(|value| value.to_str().ok())
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty());
+            if let Some(version) = header {
+                return Some((index, version));
+            }
+        }
+
+        if let Ok(json) = fetch_json(&ApiRoute::OpenApiSpec.url(base)).await {
+            let version = ApiSpec::from_json(&json).version;
+            if !version.is_empty() {
+                return Some((index, version));
+            }
+        }
+    }
+
+    None
+}
+
+async fn is_reachable(url: &str) -> bool {
+    match reqwest::get(url).await {
+        Ok(response) => response.status().is_success(),
+        Err(_) => false,
+    }
+}
+
+async fn is_ready(base: &str) -> bool {
+    is_reachable(&ApiRoute::Health(HealthCheck::Ready).url(base)).await
+}
+
+/// The URL of the first backend actually serving `tool`, if any
+pub async fn find_docs(backends: &[String], tool: DocsTool) -> Option<String> {
+    for base in backends {
+        let url = ApiRoute::Docs(tool).url(base);
+        if is_reachable(&url).await {
+            return Some(url);
+        }
+    }
+    None
+}
+
+/// Fetch a word, failing over across `backends` in order.
+///
+/// The `preferred` backend (the one that answered last) is tried first without
+/// a health probe. Any other backend is only tried once its readiness check
+/// passes. Returns the index of the backend that served the word.
+///
+/// Every word request made along the way is reported to `on_request`.
+pub async fn get_word(
+    backends: &[String],
+    preferred: usize,
+    lang: LanguageCode,
+    word_type: GrammaticalType,
+    on_request: impl Fn(RequestLog),
+) -> Result<(usize, Word), ApiError> {
+    if backends.is_empty() {
+        return Err(ApiError::Network("No backend configured".to_string()));
+    }
+
+    let preferred = preferred.min(backends.len() - 1);
+    let order = std::iter::once(preferred).chain((0..backends.len()).filter(|&i| i != preferred));
+    let mut last_error = ApiError::NoWords;
+
+    for index in order {
+        let base = &backends[index];
+        if index != preferred && !is_ready(base).await {
+            continue;
+        }
+
+        match fetch_word(base, lang, word_type, &on_request).await {
+            Ok(word) => return Ok((index, word)),
+            Err(e) if e.is_backend_failure() => last_error = e,
+            Err(e) => return Err(e),
+        }
+    }
+
+    Err(last_error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const BASE: &str = "https://api.example.com";
+
+    fn pairs(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect()
+    }
+
+    /// Reverses `encode_segment`
+    fn decode(encoded: &str) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        let mut rest = encoded.as_bytes();
+        while let Some((&byte, tail)) = rest.split_first() {
+            if byte == b'%' {
+                let hex = std::str::from_utf8(&tail[..2]).unwrap();
+                bytes.push(u8::from_str_radix(hex, 16).unwrap());
+                rest = &tail[2..];
+            } else {
+                bytes.push(byte);
+                rest = tail;
+            }
+        }
+        bytes
+    }
+
+    fn lookup(word: &str) -> ApiRoute {
+        ApiRoute::Lookup {
+            lang: LanguageCode::English,
+            word: word.to_string(),
+        }
+    }
+
+    #[test]
+    fn encode_segment_keeps_unreserved_characters() {
+        let unreserved = "ABCXYZabcxyz0189-._~";
+        assert_eq!(encode_segment(unreserved), unreserved);
+        assert_eq!(encode_segment(""), "");
+    }
+
+    #[test]
+    fn encode_segment_escapes_reserved_characters() {
+        for (raw, encoded) in [
+            (" ", "%20"),
+            ("/", "%2F"),
+            ("?", "%3F"),
+            ("#", "%23"),
+            ("&", "%26"),
+            ("=", "%3D"),
+            ("+", "%2B"),
+            ("%", "%25"),
+            (":", "%3A"),
+            ("@", "%40"),
+            ("a/b?c=d&e", "a%2Fb%3Fc%3Dd%26e"),
+        ] {
+            assert_eq!(encode_segment(raw), encoded, "{:?}", raw);
+        }
+    }
+
+    #[test]
+    fn encode_segment_escapes_non_ascii_as_utf8() {
+        assert_eq!(encode_segment("Straße"), "Stra%C3%9Fe");
+        assert_eq!(encode_segment("café"), "caf%C3%A9");
+        assert_eq!(encode_segment("日本"), "%E6%97%A5%E6%9C%AC");
+    }
+
+    #[test]
+    fn route_paths() {
+        for (route, path) in [
+            (
+                ApiRoute::Random {
+                    lang: LanguageCode::English,
+                    word_type: GrammaticalType::Noun,
+                },
+                "/en/noun",
+            ),
+            (lookup("serendipity"), "/en/word/serendipity"),
+            (lookup("Straße"), "/en/word/Stra%C3%9Fe"),
+            (lookup("café au lait"), "/en/word/caf%C3%A9%20au%20lait"),
+            (lookup("../admin"), "/en/word/..%2Fadmin"),
+            (ApiRoute::Health(HealthCheck::Alive), "/health/alive"),
+            (ApiRoute::Health(HealthCheck::Ready), "/health/ready"),
+            (ApiRoute::OpenApiSpec, "/api-docs/openapi.json"),
+            (ApiRoute::Docs(DocsTool::SwaggerUi), "/swagger-ui"),
+        ] {
+            assert_eq!(route.path(), path);
+        }
+    }
+
+    #[test]
+    fn random_paths_cover_every_language_and_type() {
+        for lang in LanguageCode::all() {
+            for word_type in GrammaticalType::all() {
+                let route = ApiRoute::Random {
+                    lang: *lang,
+                    word_type: *word_type,
+                };
+                assert_eq!(
+                    route.path(),
+                    format!("/{}/{}", lang.code(), word_type.api_name())
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn url_joins_base_and_path() {
+        let route = lookup("café");
+        let expected = "https://api.example.com/en/word/caf%C3%A9";
+        assert_eq!(route.url(BASE), expected);
+        assert_eq!(route.url("https://api.example.com/"), expected);
+        assert_eq!(route.url("https://api.example.com//"), expected);
+        assert_eq!(route.url("/api/"), "/api/en/word/caf%C3%A9");
+        assert_eq!(route.url(""), "/en/word/caf%C3%A9");
+    }
+
+    #[test]
+    fn url_with_query_encodes_keys_and_values() {
+        let route = ApiRoute::Health(HealthCheck::Alive);
+        let query = pairs(&[("q", "a b&c"), ("wört", "Straße"), ("empty", "")]);
+        assert_eq!(
+            route.url_with_query(&format!("{}/", BASE), &query),
+            "https://api.example.com/health/alive?q=a%20b%26c&w%C3%B6rt=Stra%C3%9Fe&empty="
+        );
+    }
+
+    #[test]
+    fn url_with_query_skips_empty_keys() {
+        let route = ApiRoute::OpenApiSpec;
+        let query = pairs(&[("", "dropped"), ("  ", "dropped"), (" limit ", "5")]);
+        assert_eq!(
+            route.url_with_query(BASE, &query),
+            "https://api.example.com/api-docs/openapi.json?limit=5"
+        );
+        assert_eq!(
+            route.url_with_query(BASE, &pairs(&[("", "dropped")])),
+            route.url(BASE)
+        );
+        assert_eq!(route.url_with_query(BASE, &[]), route.url(BASE));
+    }
+
+    #[test]
+    fn playground_request_urls() {
+        let request = PlaygroundRequest {
+            endpoint: Endpoint::Lookup,
+            word: "Straße".to_string(),
+            query: pairs(&[("", "x"), ("verbose", "true")]),
+            ..PlaygroundRequest::default()
+        };
+        assert_eq!(
+            request.url("/api/"),
+            "/api/en/word/Stra%C3%9Fe?verbose=true"
+        );
+    }
+
+    /// A breaker after `FAILURE_THRESHOLD` failures
+    fn open_breaker() -> CircuitBreaker {
+        let mut breaker = CircuitBreaker::default();
+        for _ in 0..FAILURE_THRESHOLD {
+            assert!(breaker.start_request());
+            breaker.record_failure();
+        }
+        breaker
+    }
+
+    /// A breaker whose cooldown just ran out
+    fn half_open_breaker() -> CircuitBreaker {
+        let mut breaker = open_breaker();
+        while !breaker.tick() {}
+        breaker
+    }
+
+    #[test]
+    fn breaker_counts_failures_while_closed() {
+        let mut breaker = CircuitBreaker::default();
+        for failures in 1..FAILURE_THRESHOLD {
+            assert!(breaker.start_request());
+            breaker.record_failure();
+            assert_eq!(breaker.state(), CircuitState::Closed { failures });
+            assert!(breaker.allows_request());
+        }
+
+        breaker.record_success();
+        assert_eq!(breaker.state(), CircuitState::Closed { failures: 0 });
+    }
+
+    #[test]
+    fn breaker_opens_after_consecutive_failures() {
+        let mut breaker = open_breaker();
+        assert_eq!(
+            breaker.state(),
+            CircuitState::Open {
+                remaining_secs: COOLDOWN_SECS
+            }
+        );
+        assert!(!breaker.allows_request());
+        assert!(!breaker.start_request());
+
+        // Late answers to requests sent before it opened don't extend it
+        assert!(!breaker.tick());
+        breaker.record_failure();
+        assert_eq!(
+            breaker.state(),
+            CircuitState::Open {
+                remaining_secs: COOLDOWN_SECS - 1
+            }
+        );
+    }
+
+    #[test]
+    fn breaker_goes_half_open_after_the_cooldown() {
+        let mut breaker = open_breaker();
+        for remaining_secs in (1..COOLDOWN_SECS).rev() {
+            assert!(!breaker.tick());
+            assert_eq!(breaker.state(), CircuitState::Open { remaining_secs });
+        }
+        assert!(breaker.tick());
+        assert_eq!(breaker.state(), CircuitState::HalfOpen { probing: false });
+        assert!(breaker.allows_request());
+
+        // Only once
+        assert!(!breaker.tick());
+    }
+
+    #[test]
+    fn half_open_breaker_lets_a_single_probe_through() {
+        let mut breaker = half_open_breaker();
+        assert!(breaker.start_request());
+        assert_eq!(breaker.state(), CircuitState::HalfOpen { probing: true });
+        assert!(!breaker.allows_request());
+        assert!(!breaker.start_request());
+        assert!(!breaker.tick());
+        assert_eq!(breaker.state(), CircuitState::HalfOpen { probing: true });
+    }
+
+    #[test]
+    fn successful_probe_closes_the_breaker() {
+        let mut breaker = half_open_breaker();
+        assert!(breaker.start_request());
+        breaker.record_success();
+        assert_eq!(breaker.state(), CircuitState::Closed { failures: 0 });
+        assert!(breaker.start_request());
+        assert!(breaker.start_request());
+    }
+
+    #[test]
+    fn failed_probe_opens_the_breaker_again() {
+        let mut breaker = half_open_breaker();
+        assert!(breaker.start_request());
+        breaker.record_failure();
+        assert_eq!(
+            breaker.state(),
+            CircuitState::Open {
+                remaining_secs: COOLDOWN_SECS
+            }
+        );
+        assert!(!breaker.start_request());
+    }
+
+    proptest! {
+        #[test]
+        fn encode_segment_is_reversible(segment in any::<String>()) {
+            let encoded = encode_segment(&segment);
+            prop_assert!(encoded
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || b"-._~%".contains(&b)));
+            prop_assert_eq!(decode(&encoded), segment.as_bytes());
+        }
+
+        #[test]
+        fn lookup_paths_stay_one_segment(word in any::<String>()) {
+            let path = lookup(&word).path();
+            let segment = path.strip_prefix("/en/word/").unwrap();
+            prop_assert!(!segment.contains('/'));
+            prop_assert!(!segment.contains('?'));
+            prop_assert!(!segment.contains('#'));
+        }
+    }
+
+    /// Nothing listens on port 1, so requests fail without leaving the machine
+    const UNREACHABLE: &str = "http://127.0.0.1:1";
+
+    #[tokio::test]
+    async fn failed_requests_are_timed_off_the_browser() {
+        let logs = std::cell::RefCell::new(Vec::new());
+        let result = fetch_word(
+            UNREACHABLE,
+            LanguageCode::English,
+            GrammaticalType::Noun,
+            &|log| logs.borrow_mut().push(log),
+        )
+        .await;
+        assert!(matches!(result, Err(ApiError::Network(_))));
+        let logs = logs.into_inner();
+        assert_eq!(logs.len(), 1);
+        assert_eq!(logs[0].url, format!("{}/en/noun", UNREACHABLE));
+        assert!(logs[0].elapsed_ms >= 0.0);
+
+        let log = send_request(UNREACHABLE, &[]).await;
+        assert_eq!(log.status, None);
+        assert!(!log.body.is_empty());
+        assert!(log.elapsed_ms >= 0.0);
+    }
+
+    fn headers(pairs: &[(&'static str, &'static str)]) -> reqwest::header::HeaderMap {
+        pairs
+            .iter()
+            .map(|(name, value)| {
+                (
+                    reqwest::header::HeaderName::from_static(name),
+                    reqwest::header::HeaderValue::from_static(value),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn retry_after_falls_back_to_a_minute() {
+        assert_eq!(DEFAULT_RETRY_AFTER_SECS, 60);
+        assert_eq!(retry_after_secs(&headers(&[])), 60);
+        assert_eq!(
+            retry_after_secs(&headers(&[(
+                "retry-after",
+                "Wed, 21 Oct 2026 07:28:00 GMT"
+            )])),
+            60
+        );
+    }
+
+    #[test]
+    fn retry_after_reads_either_header() {
+        assert_eq!(retry_after_secs(&headers(&[("retry-after", " 12 ")])), 12);
+        assert_eq!(retry_after_secs(&headers(&[("ratelimit-reset", "30")])), 30);
+        assert_eq!(
+            retry_after_secs(&headers(&[("retry-after", "5"), ("ratelimit-reset", "30")])),
+            5
+        );
+    }
+
+    fn compatible(versions: &[&str]) -> Vec<bool> {
+        versions
+            .iter()
+            .map(|version| is_compatible_api_version(version))
+            .collect()
+    }
+
+    #[test]
+    fn same_api_version_is_compatible() {
+        assert_eq!(SUPPORTED_API_VERSION, "0.1.0");
+        assert_eq!(compatible(&["0.1.0", " 0.1.0 ", "0.1"]), [true; 3]);
+        assert_eq!(compatible(&["0.1.7"]), [true]);
+    }
+
+    #[test]
+    fn minor_api_bump_before_1_0_is_incompatible() {
+        assert_eq!(compatible(&["0.2.0", "0.0.9"]), [false; 2]);
+    }
+
+    #[test]
+    fn major_api_bump_is_incompatible() {
+        assert_eq!(compatible(&["1.0.0", "1.1.0", "2.0.0"]), [false; 3]);
+    }
+
+    #[test]
+    fn api_version_may_have_a_v_prefix() {
+        assert_eq!(compatible(&["v0.1.0", "v0.1.3"]), [true; 2]);
+        assert_eq!(compatible(&["v0.2.0", "v1.0.0"]), [false; 2]);
+    }
+
+    #[test]
+    fn unparsable_api_version_is_incompatible() {
+        assert_eq!(
+            compatible(&["", "v", "latest", "x.1.0", "0.x", "-1.0.0"]),
+            [false; 6]
+        );
+    }
+}
@@ -1,29 +1,16 @@
 use icondata as i;
 use leptos::prelude::*;
 use leptos_icons::Icon;
+use leptos_meta::{provide_meta_context, Link, Meta, Script, Title};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
 
+use crate::api::*;
 use crate::model::*;
-
-async fn get_word(dict_lang: &str, word_type: &str) -> Result<Word, String> {
-    let uri = format!(
-        "{}/{}/{}",
-        api_url(),
-        dict_lang.to_lowercase(),
-        word_type.to_lowercase()
-    );
-    let response = reqwest::get(&uri).await.map_err(|e| e.to_string())?;
-
-    match response.json::<Vec<Word>>().await {
-        Ok(words) => {
-            if let Some(word) = words.into_iter().next() {
-                Ok(word)
-            } else {
-                Err("No words found in response".to_string())
-            }
-        }
-        Err(e) => Err(format!("Failed to parse JSON: {}", e)),
-    }
-}
+use crate::openapi::*;
+use crate::seo::*;
+use crate::snippets::*;
 
 #[component]
 fn Navbar() -> impl IntoView {
@@ -201,30 +188,9 @@ fn Landing() -> impl IntoView {
                     </a>
                     " family of crates - the following OpenAPI tools:"
                 </p>
-                <div class="flex mt-5">
-                    <ul class="flex mx-auto">
-                        <li class="pr-1">
-                            <button class="btn btn-sm btn-outline md:btn-md">
-                                <a href="/swagger-ui">"SwaggerUI"</a>
-                            </button>
-                        </li>
-                        <li class="px-1">
-                            <button class="btn btn-sm btn-outline md:btn-md">
-                                <a href="/scalar">"Scalar"</a>
-                            </button>
-                        </li>
-                        <li class="px-1">
-                            <button class="btn btn-sm btn-outline md:btn-md">
-                                <a href="/redoc">"Redoc"</a>
-                            </button>
-                        </li>
-                        <li class="pl-1">
-                            <button class="btn btn-sm btn-outline md:btn-md">
-                                <a href="/rapidoc">"RapiDoc"</a>
-                            </button>
-                        </li>
-                    </ul>
-                </div>
+                <DocsHub />
+                <ApiReference />
+                <Playground />
             </div>
         </div>
     }
@@ -236,77 +202,279 @@ struct GrammTypeSetter(WriteSignal<GrammaticalType>);
 #[derive(Clone, Copy)]
 struct DictLangSetter(WriteSignal<LanguageCode>);
 
+type WordFuture = Pin<Box<dyn Future<Output = Result<(usize, Word), ApiError>>>>;
+
+/// Where `Demo` gets its words: the backends in `API_URL`, unless another
+/// source is provided as context, as the tests do
+#[derive(Clone)]
+struct WordSource(
+    Arc<
+        dyn Fn(usize, LanguageCode, GrammaticalType, Callback<RequestLog>) -> WordFuture
+            + Send
+            + Sync,
+    >,
+);
+
+impl WordSource {
+    fn new(
+        fetch: impl Fn(usize, LanguageCode, GrammaticalType, Callback<RequestLog>) -> WordFuture
+            + Send
+            + Sync
+            + 'static,
+    ) -> Self {
+        WordSource(Arc::new(fetch))
+    }
+
+    /// The backends in `API_URL`, starting from the `preferred` one
+    fn backends() -> Self {
+        WordSource::new(|preferred, lang, word_type, on_request| {
+            Box::pin(async move {
+                get_word(&api_urls(), preferred, lang, word_type, move |log| {
+                    on_request.run(log)
+                })
+                .await
+            })
+        })
+    }
+}
+
+/// The backends as shown on the page. Relative ones only resolve against the
+/// page's origin in the browser, so they're shown as configured until mounted
+/// and the server-rendered text matches what hydration expects.
+fn shown_api_urls() -> ReadSignal<Vec<String>> {
+    let (urls, set_urls) = signal(configured_api_urls());
+    Effect::new(move |_| set_urls.set(api_urls()));
+    urls
+}
+
 #[component]
 fn Demo() -> impl IntoView {
     let (gramm_type, set_gramm_type) = signal(GrammaticalType::Random);
     let (dict_lang, set_dict_lang) = signal(LanguageCode::English);
     let (word_data, set_word_data) = signal(None::<Word>);
-    let (error, set_error) = signal(None::<String>);
+    let (error, set_error) = signal(None::<ApiError>);
 
     provide_context(GrammTypeSetter(set_gramm_type));
     provide_context(DictLangSetter(set_dict_lang));
 
-    // Load random word on page load
-    Effect::new(move |_| {
-        let word_type = gramm_type.get().api_name();
-        let lang = dict_lang.get().code();
+    let backends = shown_api_urls();
+    let (active_backend, set_active_backend) = signal(0usize);
+    let breaker = RwSignal::new(CircuitBreaker::default());
+    let cooldown = RwSignal::new(0u32);
+    let queued = RwSignal::new(false);
+    let (requests, set_requests) = signal(Vec::<RequestLog>::new());
+    let source = StoredValue::new(use_context::<WordSource>().unwrap_or_else(WordSource::backends));
+    let on_request = Callback::new(move |log| {
+        set_requests.update(|logs: &mut Vec<RequestLog>| {
+            logs.insert(0, log);
+            logs.truncate(MAX_LOGGED_REQUESTS);
+        })
+    });
+
+    let load_word = move |lang: LanguageCode, word_type: GrammaticalType| {
+        if !breaker.get_untracked().allows_request() {
+            return;
+        }
+
+        // Rate limited: hold the request until the limit resets
+        if cooldown.get_untracked() > 0 {
+            queued.set(true);
+            return;
+        }
+
+        // Half-open this is the probe, further requests wait until it's done
+        let mut allowed = false;
+        breaker.update(|b| allowed = b.start_request());
+        if !allowed {
+            return;
+        }
+
+        let preferred = active_backend.get_untracked();
         set_error.set(None);
 
+        let word = source.with_value(|source| (source.0)(preferred, lang, word_type, on_request));
         leptos::task::spawn_local(async move {
-            match get_word(lang, word_type).await {
-                Ok(word) => {
+            match word.await {
+                Ok((backend, word)) => {
+                    breaker.update(|b| b.record_success());
+                    set_active_backend.set(backend);
                     set_word_data.set(Some(word));
                 }
+                Err(ApiError::RateLimited { retry_after_secs }) => {
+                    breaker.update(|b| b.record_success());
+                    cooldown.set(retry_after_secs);
+                    queued.set(true);
+                }
                 Err(e) => {
-                    set_error.set(Some(e));
+                    if e.is_backend_failure() {
+                        breaker.update(|b| b.record_failure());
+                    } else {
+                        breaker.update(|b| b.record_success());
+                    }
+                    // While the circuit is open keep showing the last word we got
+                    let cached = word_data.get_untracked().is_some();
+                    if !(cached && !breaker.get_untracked().allows_request()) {
+                        set_error.set(Some(e));
+                    }
                 }
             }
         });
-    });
+    };
 
-    let fetch_word = move |_| {
-        let word_type = gramm_type.get().api_name();
-        let lang = dict_lang.get().code();
-        set_error.set(None);
+    // Load random word on page load
+    Effect::new(move |_| load_word(dict_lang.get(), gramm_type.get()));
 
-        leptos::task::spawn_local(async move {
-            match get_word(lang, word_type).await {
-                Ok(word) => {
-                    set_word_data.set(Some(word));
+    let fetch_word = move |_| load_word(dict_lang.get_untracked(), gramm_type.get_untracked());
+
+    // Count down an open circuit and the rate limit cooldown, then send the
+    // half-open probe or the queued request when they expire. Started from an
+    // effect so it only ever runs in the browser.
+    #[cfg(target_arch = "wasm32")]
+    Effect::new(move |_| {
+        if let Ok(handle) = set_interval_with_handle(
+            move || {
+                let mut half_open = false;
+                breaker.update(|b| half_open = b.tick());
+
+                let mut limit_reset = false;
+                if cooldown.get_untracked() > 0 {
+                    cooldown.update(|secs| *secs -= 1);
+                    limit_reset = cooldown.get_untracked() == 0 && queued.get_untracked();
                 }
-                Err(e) => {
-                    set_error.set(Some(e));
+
+                if half_open || limit_reset {
+                    queued.set(false);
+                    load_word(dict_lang.get_untracked(), gramm_type.get_untracked());
                 }
-            }
-        });
-    };
+            },
+            std::time::Duration::from_secs(1),
+        ) {
+            on_cleanup(move || handle.clear());
+        }
+    });
 
     view! {
-        <div class="flex flex-col" aria-label="main area to display a random word">
+        <div
+            class="flex flex-col"
+            aria-label="main area to display a random word"
+            data-testid="demo"
+        >
+            {move || match breaker.get().state() {
+                CircuitState::Open { remaining_secs } => {
+                    Some(
+                        view! {
+                            <div role="alert" class="mt-2 alert alert-warning alert-soft">
+                                <Icon icon=i::FaCircleExclamationSolid />
+                                <span>
+                                    "The backend keeps failing, requests are paused. Retrying in "
+                                    {remaining_secs} "s."
+                                </span>
+                            </div>
+                        },
+                    )
+                }
+                _ => None,
+            }}
+            {move || {
+                (cooldown.get() > 0)
+                    .then(|| {
+                        view! {
+                            <div role="status" class="mt-2 alert alert-info alert-soft">
+                                <Icon icon=i::FaCircleExclamationSolid />
+                                <span>
+                                    "Rate limit reached. Your request is queued and will be sent in "
+                                    {cooldown.get()} "s."
+                                </span>
+                            </div>
+                        }
+                    })
+            }}
             <div class="justify-items-start p-4 m-auto mt-2 w-full border-2 bg-base-200 border-base-300">
                 {move || {
                     match (error.get(), word_data.get()) {
                         (Some(err), _) => {
                             view! {
                                 <div>
-                                    <p class="p-1 text-error">"Error: " {err}</p>
+                                    <p class="p-1 text-error">"Error: " {err.to_string()}</p>
                                 </div>
                             }
                                 .into_any()
                         }
                         (None, Some(word)) => {
+                            let lang = word.language.unwrap_or(dict_lang.get()).code();
                             view! {
-                                <div>
+                                <div lang=lang>
                                     <p class="p-1">"word: " {word.word.clone()}</p>
                                 </div>
-                                <div>
+                                <div lang=lang>
                                     <p class="p-1">"definition: " {word.definition.clone()}</p>
                                 </div>
-                                <div>
+                                <div lang=lang>
                                     <p class="p-1">
                                         "pronunciation: " {word.pronunciation.clone()}
                                     </p>
                                 </div>
+                                {word
+                                    .grammatical_type
+                                    .map(|g_type| {
+                                        view! {
+                                            <div>
+                                                <p class="p-1">"type: " {g_type.name()}</p>
+                                            </div>
+                                        }
+                                    })}
+                                {word
+                                    .language
+                                    .map(|language| {
+                                        view! {
+                                            <div>
+                                                <p class="p-1">"language: " {language.name()}</p>
+                                            </div>
+                                        }
+                                    })}
+                                {(!word.syllables.is_empty())
+                                    .then(|| {
+                                        view! {
+                                            <div lang=lang>
+                                                <p class="p-1">
+                                                    "syllables: " {word.syllables.join(" · ")}
+                                                </p>
+                                            </div>
+                                        }
+                                    })}
+                                {(!word.examples.is_empty())
+                                    .then(|| {
+                                        view! {
+                                            <div lang=lang>
+                                                <p class="p-1">"examples:"</p>
+                                                <ul class="pl-6 list-disc">
+                                                    {word
+                                                        .examples
+                                                        .iter()
+                                                        .map(|example| view! { <li>{example.clone()}</li> })
+                                                        .collect::<Vec<_>>()}
+                                                </ul>
+                                            </div>
+                                        }
+                                    })}
+                                {word
+                                    .id
+                                    .map(|id| {
+                                        view! {
+                                            <div>
+                                                <p class="p-1 text-sm opacity-60">"id: " {id}</p>
+                                            </div>
+                                        }
+                                    })}
+                                <div>
+                                    <p class="p-1 text-sm opacity-60">
+                                        "served by: "
+                                        {backends
+                                            .with(|urls| {
+                                                urls.get(active_backend.get()).cloned().unwrap_or_default()
+                                            })}
+                                    </p>
+                                </div>
                             }
                                 .into_any()
                         }
@@ -331,7 +499,9 @@ fn Demo() -> impl IntoView {
                                 let flag_class = format!("fi fi-{} fis mr-2", lang.flag_code());
                                 view! {
                                     <span class=flag_class></span>
-                                    <span class="hidden sm:inline">{lang.name()}</span>
+                                    <span class="hidden sm:inline" lang=lang.code()>
+                                        {lang.name()}
+                                    </span>
                                 }
                             }}
                             <Icon icon=i::FaChevronDownSolid />
@@ -369,16 +539,824 @@ fn Demo() -> impl IntoView {
                                 .collect::<Vec<_>>()}
                         </ul>
                     </div>
-                    <button class="ml-1 btn btn-sm btn-outline md:btn-md" on:click=fetch_word>
+                    <button
+                        class="ml-1 btn btn-sm btn-outline md:btn-md"
+                        disabled=move || { !breaker.get().allows_request() || cooldown.get() > 0 }
+                        on:click=fetch_word
+                    >
                         "New "
                         {move || gramm_type.get().name()}
+                        {move || {
+                            let secs = cooldown.get();
+                            (secs > 0).then(|| format!(" ({}s)", secs))
+                        }}
+                    </button>
+                </div>
+            </div>
+            <CodeSnippets url=Signal::derive(move || {
+                let base = backends
+                    .with(|urls| urls.get(active_backend.get()).cloned().unwrap_or_default());
+                ApiRoute::Random {
+                    lang: dict_lang.get(),
+                    word_type: gramm_type.get(),
+                }
+                    .url(&base)
+            }) />
+            <RequestInspector requests=requests />
+        </div>
+    }
+}
+
+#[component]
+fn CodeSnippets(url: Signal<String>) -> impl IntoView {
+    let (language, set_language) = signal(SnippetLanguage::Curl);
+    let (copied, set_copied) = signal(false);
+    let code = move || language.get().snippet(&url.get());
+
+    let copy_code = move |_| {
+        let _ = window().navigator().clipboard().write_text(&code());
+        set_copied.set(true);
+    };
+
+    view! {
+        <div
+            class="mt-2 border-2 bg-base-200 border-base-300"
+            aria-label="code snippets to fetch the selected word type and language"
+        >
+            <div role="tablist" class="tabs tabs-border">
+                {SnippetLanguage::all()
+                    .iter()
+                    .map(|&lang| {
+                        view! {
+                            <button
+                                role="tab"
+                                class="tab"
+                                class:tab-active=move || language.get() == lang
+                                aria-selected=move || (language.get() == lang).to_string()
+                                on:click=move |_| {
+                                    set_language.set(lang);
+                                    set_copied.set(false);
+                                }
+                            >
+                                {lang.name()}
+                            </button>
+                        }
+                    })
+                    .collect::<Vec<_>>()}
+            </div>
+            <div class="relative">
+                <pre class="overflow-x-auto p-4 text-sm">
+                    <code>{code}</code>
+                </pre>
+                <button
+                    class="absolute top-2 right-2 btn btn-xs btn-outline"
+                    aria-label="copy snippet to clipboard"
+                    on:click=copy_code
+                >
+                    {move || if copied.get() { "Copied" } else { "Copy" }}
+                </button>
+            </div>
+        </div>
+    }
+}
+
+#[component]
+fn DocsHub() -> impl IntoView {
+    let backends = StoredValue::new(api_urls());
+    let probes: Vec<_> = DocsTool::all()
+        .iter()
+        .map(|&tool| {
+            let probe =
+                LocalResource::new(
+                    move || async move { find_docs(&backends.get_value(), tool).await },
+                );
+            (tool, probe)
+        })
+        .collect();
+    let any_missing = {
+        let probes = probes.clone();
+        move || probes.iter().any(|(_, probe)| probe.get() == Some(None))
+    };
+
+    view! {
+        <ul
+            class="flex flex-wrap gap-2 justify-center mt-5"
+            aria-label="OpenAPI documentation tools and their availability"
+        >
+            {probes
+                .into_iter()
+                .map(|(tool, probe)| {
+                    view! {
+                        <li>
+                            {move || match probe.get() {
+                                None => {
+                                    view! {
+                                        <span
+                                            class="btn btn-sm btn-outline btn-disabled md:btn-md"
+                                            aria-busy="true"
+                                        >
+                                            {tool.name()}
+                                            <span class="loading loading-spinner loading-xs"></span>
+                                        </span>
+                                    }
+                                        .into_any()
+                                }
+                                Some(Some(url)) => {
+                                    view! {
+                                        <a
+                                            href=url
+                                            target="_blank"
+                                            rel="noopener noreferrer"
+                                            class="btn btn-sm btn-outline md:btn-md"
+                                        >
+                                            {tool.name()}
+                                            <span class="badge badge-success badge-xs">"available"</span>
+                                        </a>
+                                    }
+                                        .into_any()
+                                }
+                                Some(None) => {
+                                    view! {
+                                        <span
+                                            class="btn btn-sm btn-outline btn-disabled md:btn-md"
+                                            aria-disabled="true"
+                                        >
+                                            {tool.name()}
+                                            <span class="badge badge-ghost badge-xs">"unavailable"</span>
+                                        </span>
+                                    }
+                                        .into_any()
+                                }
+                            }}
+                        </li>
+                    }
+                })
+                .collect::<Vec<_>>()}
+        </ul>
+        <Show when=any_missing>
+            <div class="mt-4 text-base">
+                <p>
+                    "Tools marked unavailable aren't served by the configured backends. To browse them, run the backend yourself from the "
+                    <a href=REPO_URL target="_blank" rel="noopener noreferrer" class="link link-primary">
+                        "Random Word API"
+                    </a> " repository:"
+                </p>
+                <ul class="pl-6 mt-1 list-disc">
+                    <li>"locally, with " <code>"cargo run"</code></li>
+                    <li>"in Docker, with " <code>"docker compose up"</code></li>
+                </ul>
+                <p class="mt-1">
+                    "then build this page with " <code>"API_URL"</code> " pointing at it."
+                </p>
+            </div>
+        </Show>
+    }
+}
+
+#[component]
+fn ApiReference() -> impl IntoView {
+    let backends = StoredValue::new(api_urls());
+    let spec =
+        LocalResource::new(move || async move { get_openapi_spec(&backends.get_value()).await });
+    let (search, set_search) = signal(String::new());
+
+    view! {
+        <div class="mt-8" aria-label="API reference rendered from the backend's OpenAPI document">
+            <h3 class="text-base font-semibold md:text-lg">"API Reference"</h3>
+            <Suspense fallback=|| {
+                view! { <span class="loading loading-spinner loading-md"></span> }
+            }>
+                {move || {
+                    spec.get()
+                        .map(|result| match result {
+                            Ok((backend, spec)) => {
+                                let base = backends
+                                    .with_value(|urls| urls.get(backend).cloned().unwrap_or_default());
+                                let operations = StoredValue::new(spec.operations);
+                                let schemas = StoredValue::new(spec.schemas);
+                                view! {
+                                    <p class="text-base">
+                                        {spec.title} " " <span class="badge badge-outline">
+                                            {spec.version}
+                                        </span>
+                                    </p>
+                                    <input
+                                        type="search"
+                                        class="mt-2 w-full input input-sm md:input-md"
+                                        placeholder="Search endpoints and schemas"
+                                        aria-label="search the API reference"
+                                        on:input=move |ev| set_search.set(event_target_value(&ev))
+                                    />
+                                    <h4 class="mt-4 font-semibold">"Endpoints"</h4>
+                                    {move || {
+                                        let base = base.clone();
+                                        operations
+                                            .with_value(|operations| {
+                                                operations
+                                                    .iter()
+                                                    .filter(|op| op.matches(&search.get()))
+                                                    .map(|op| {
+                                                        view! {
+                                                            <OperationEntry operation=op.clone() base=base.clone() />
+                                                        }
+                                                    })
+                                                    .collect::<Vec<_>>()
+                                            })
+                                    }}
+                                    <h4 class="mt-4 font-semibold">"Schemas"</h4>
+                                    {move || {
+                                        schemas
+                                            .with_value(|schemas| {
+                                                schemas
+                                                    .iter()
+                                                    .filter(|schema| schema.matches(&search.get()))
+                                                    .map(|schema| view! { <SchemaEntry schema=schema.clone() /> })
+                                                    .collect::<Vec<_>>()
+                                            })
+                                    }}
+                                }
+                                    .into_any()
+                            }
+                            Err(e) => {
+                                view! {
+                                    <p class="text-error">
+                                        "The OpenAPI document couldn't be loaded: " {e.to_string()}
+                                    </p>
+                                }
+                                    .into_any()
+                            }
+                        })
+                }}
+            </Suspense>
+        </div>
+    }
+}
+
+#[component]
+fn OperationEntry(operation: Operation, base: String) -> impl IntoView {
+    let operation = StoredValue::new(operation);
+    let values = RwSignal::new(Vec::<(String, String)>::new());
+    let (response, set_response) = signal(None::<RequestLog>);
+
+    let set_value = move |name: String, value: String| {
+        values.update(|values| {
+            values.retain(|(key, _)| *key != name);
+            values.push((name, value));
+        })
+    };
+
+    let try_it = move |_| {
+        let (url, headers) = operation.with_value(|op| {
+            values.with_untracked(|values| (op.url(&base, values), op.headers(values)))
+        });
+
+        leptos::task::spawn_local(async move {
+            set_response.set(Some(send_request(&url, &headers).await));
+        });
+    };
+
+    operation
+        .with_value(|op| {
+            view! {
+                <details class="mt-2 border-2 collapse collapse-arrow bg-base-200 border-base-300">
+                    <summary class="font-mono collapse-title">
+                        <span class="mr-2 badge badge-primary">{op.method.clone()}</span>
+                        {op.path.clone()}
+                        <span class="ml-2 font-sans text-sm opacity-70">{op.summary.clone()}</span>
+                    </summary>
+                    <div class="text-sm collapse-content">
+                        <p>{op.description.clone()}</p>
+                        {op
+                            .parameters
+                            .iter()
+                            .map(|parameter| {
+                                let name = parameter.name.clone();
+                                view! {
+                                    <label class="flex gap-2 items-center mt-1">
+                                        <span class="w-40 font-mono">
+                                            {parameter.name.clone()}
+                                            {parameter.required.then_some("*")}
+                                        </span>
+                                        <span class="w-24 opacity-70">
+                                            {format!("{} · {}", parameter.location, parameter.type_name)}
+                                        </span>
+                                        <input
+                                            type="text"
+                                            class="input input-xs"
+                                            placeholder=parameter.description.clone()
+                                            on:change=move |ev| {
+                                                set_value(name.clone(), event_target_value(&ev))
+                                            }
+                                        />
+                                    </label>
+                                }
+                            })
+                            .collect::<Vec<_>>()}
+                        <button class="mt-2 btn btn-xs btn-outline" on:click=try_it>
+                            "Try it"
+                        </button>
+                        {move || response.get().map(|log| view! { <RequestLogEntry log=log /> })}
+                    </div>
+                </details>
+            }
+        })
+}
+
+#[component]
+fn SchemaEntry(schema: Schema) -> impl IntoView {
+    view! {
+        <details class="mt-2 border-2 collapse collapse-arrow bg-base-200 border-base-300">
+            <summary class="font-mono collapse-title">{schema.name}</summary>
+            <div class="text-sm collapse-content">
+                <p>{schema.description}</p>
+                <table class="table table-xs">
+                    <tbody>
+                        {schema
+                            .properties
+                            .into_iter()
+                            .map(|property| {
+                                view! {
+                                    <tr>
+                                        <td class="font-mono">
+                                            {property.name} {property.required.then_some("*")}
+                                        </td>
+                                        <td class="font-mono">{property.type_name}</td>
+                                        <td>{property.description}</td>
+                                    </tr>
+                                }
+                            })
+                            .collect::<Vec<_>>()}
+                    </tbody>
+                </table>
+            </div>
+        </details>
+    }
+}
+
+/// Local storage key for the playground's saved requests
+const SAVED_REQUESTS_KEY: &str = "playground-requests";
+
+/// The saved requests in `json`, skipping the ones this version can't read.
+/// `None` when `json` isn't a list at all.
+fn parse_saved_requests(json: &str) -> Option<Vec<PlaygroundRequest>> {
+    let entries: Vec<serde_json::Value> = serde_json::from_str(json).ok()?;
+    Some(
+        entries
+            .into_iter()
+            .filter_map(|entry| serde_json::from_value(entry).ok())
+            .collect(),
+    )
+}
+
+/// `None` when local storage holds something that isn't a list of requests
+fn load_saved_requests() -> Option<Vec<PlaygroundRequest>> {
+    let json = window()
+        .local_storage()
+        .ok()
+        .flatten()
+        .and_then(|storage| storage.get_item(SAVED_REQUESTS_KEY).ok().flatten());
+    match json {
+        Some(json) => parse_saved_requests(&json),
+        None => Some(Vec::new()),
+    }
+}
+
+fn store_saved_requests(requests: &[PlaygroundRequest]) {
+    let storage = window().local_storage().ok().flatten();
+    if let (Some(storage), Ok(json)) = (storage, serde_json::to_string(requests)) {
+        let _ = storage.set_item(SAVED_REQUESTS_KEY, &json);
+    }
+}
+
+#[component]
+fn Playground() -> impl IntoView {
+    let backends = shown_api_urls();
+    let (backend, set_backend) = signal(0usize);
+    let request = RwSignal::new(PlaygroundRequest::default());
+    let (response, set_response) = signal(None::<RequestLog>);
+    let (sending, set_sending) = signal(false);
+    let saved = RwSignal::new(Vec::new());
+    // Stays false when the stored requests couldn't be read, so saving
+    // doesn't overwrite them
+    let (loaded, set_loaded) = signal(false);
+    let store = move |requests: &[PlaygroundRequest]| {
+        if loaded.get_untracked() {
+            store_saved_requests(requests);
+        }
+    };
+
+    // Local storage only exists in the browser
+    Effect::new(move |_| {
+        if let Some(requests) = load_saved_requests() {
+            saved.set(requests);
+            set_loaded.set(true);
+        }
+    });
+
+    let url = move || {
+        let base = backends.with(|urls| urls.get(backend.get()).cloned().unwrap_or_default());
+        request.with(|r| r.url(&base))
+    };
+
+    let send = move |_| {
+        let url = url();
+        let headers = request.with_untracked(|r| r.headers.clone());
+        set_sending.set(true);
+
+        leptos::task::spawn_local(async move {
+            set_response.set(Some(send_request(&url, &headers).await));
+            set_sending.set(false);
+        });
+    };
+
+    let save = move |_| {
+        let mut current = request.get_untracked();
+        if current.name.trim().is_empty() {
+            current.name = current.endpoint.name().to_string();
+        }
+        saved.update(|requests| {
+            requests.retain(|r| r.name != current.name);
+            requests.push(current);
+            store(requests);
+        });
+    };
+
+    view! {
+        <div class="mt-8" aria-label="API playground to build and send any request">
+            <h3 class="text-base font-semibold md:text-lg">"Playground"</h3>
+            <p class="text-base">
+                "Build any request the backend supports, send it, and inspect the response."
+            </p>
+            <div class="flex flex-wrap gap-2 p-4 mt-2 border-2 bg-base-200 border-base-300">
+                <select
+                    class="w-auto select select-sm"
+                    aria-label="backend"
+                    on:change=move |ev| {
+                        if let Ok(index) = event_target_value(&ev).parse() {
+                            set_backend.set(index);
+                        }
+                    }
+                >
+                    {(0..backends.with_untracked(Vec::len))
+                        .map(|index| {
+                            view! {
+                                <option value=index.to_string() selected=move || backend.get() == index>
+                                    {move || {
+                                        backends.with(|urls| urls.get(index).cloned().unwrap_or_default())
+                                    }}
+                                </option>
+                            }
+                        })
+                        .collect::<Vec<_>>()}
+                </select>
+                <select
+                    class="w-auto select select-sm"
+                    aria-label="endpoint"
+                    on:change=move |ev| {
+                        let endpoint = event_target_value(&ev)
+                            .parse::<usize>()
+                            .ok()
+                            .and_then(|index| Endpoint::all().get(index));
+                        if let Some(&endpoint) = endpoint {
+                            request.update(|r| r.endpoint = endpoint);
+                        }
+                    }
+                >
+                    {Endpoint::all()
+                        .iter()
+                        .enumerate()
+                        .map(|(index, &endpoint)| {
+                            view! {
+                                <option
+                                    value=index.to_string()
+                                    selected=move || request.with(|r| r.endpoint == endpoint)
+                                >
+                                    {endpoint.name()}
+                                </option>
+                            }
+                        })
+                        .collect::<Vec<_>>()}
+                </select>
+                <Show when=move || {
+                    request.with(|r| matches!(r.endpoint, Endpoint::Random | Endpoint::Lookup))
+                }>
+                    <select
+                        class="w-auto select select-sm"
+                        aria-label="language"
+                        on:change=move |ev| {
+                            if let Ok(lang) = event_target_value(&ev).parse() {
+                                request.update(|r| r.lang = lang);
+                            }
+                        }
+                    >
+                        {LanguageCode::all()
+                            .iter()
+                            .map(|&lang| {
+                                view! {
+                                    <option
+                                        value=lang.code()
+                                        selected=move || request.with(|r| r.lang == lang)
+                                    >
+                                        {lang.name()}
+                                    </option>
+                                }
+                            })
+                            .collect::<Vec<_>>()}
+                    </select>
+                </Show>
+                <Show when=move || request.with(|r| r.endpoint == Endpoint::Random)>
+                    <select
+                        class="w-auto select select-sm"
+                        aria-label="grammatical type"
+                        on:change=move |ev| {
+                            if let Ok(word_type) = event_target_value(&ev).parse() {
+                                request.update(|r| r.word_type = word_type);
+                            }
+                        }
+                    >
+                        {GrammaticalType::all()
+                            .iter()
+                            .map(|&g_type| {
+                                view! {
+                                    <option
+                                        value=g_type.api_name()
+                                        selected=move || request.with(|r| r.word_type == g_type)
+                                    >
+                                        {g_type.name()}
+                                    </option>
+                                }
+                            })
+                            .collect::<Vec<_>>()}
+                    </select>
+                </Show>
+                <Show when=move || request.with(|r| r.endpoint == Endpoint::Lookup)>
+                    <input
+                        type="text"
+                        class="w-auto input input-sm"
+                        placeholder="word"
+                        aria-label="word to look up"
+                        prop:value=move || request.with(|r| r.word.clone())
+                        on:change=move |ev| request.update(|r| r.word = event_target_value(&ev))
+                    />
+                </Show>
+                <KeyValueEditor
+                    title="Query parameters"
+                    rows=Signal::derive(move || request.with(|r| r.query.clone()))
+                    set_rows=Callback::new(move |rows| request.update(|r| r.query = rows))
+                />
+                <KeyValueEditor
+                    title="Headers"
+                    rows=Signal::derive(move || request.with(|r| r.headers.clone()))
+                    set_rows=Callback::new(move |rows| request.update(|r| r.headers = rows))
+                />
+                <p class="w-full font-mono text-sm break-all">"GET " {url}</p>
+                <div class="flex flex-wrap gap-2 w-full">
+                    <button
+                        class="btn btn-sm btn-outline md:btn-md"
+                        disabled=sending
+                        on:click=send
+                    >
+                        "Send"
+                    </button>
+                    <input
+                        type="text"
+                        class="w-auto input input-sm md:input-md"
+                        placeholder="request name"
+                        aria-label="name to save the request under"
+                        prop:value=move || request.with(|r| r.name.clone())
+                        on:change=move |ev| request.update(|r| r.name = event_target_value(&ev))
+                    />
+                    <button class="btn btn-sm btn-outline md:btn-md" on:click=save>
+                        "Save"
                     </button>
                 </div>
             </div>
+            {move || {
+                let requests = saved.get();
+                (!requests.is_empty())
+                    .then(|| {
+                        view! {
+                            <ul class="mt-2 w-full menu bg-base-200 rounded-box">
+                                <li class="menu-title">"Saved requests"</li>
+                                {requests
+                                    .into_iter()
+                                    .map(|saved_request| {
+                                        let name = saved_request.name.clone();
+                                        view! {
+                                            <li class="flex-row">
+                                                <button
+                                                    class="flex-1"
+                                                    on:click=move |_| request.set(saved_request.clone())
+                                                >
+                                                    {saved_request.name.clone()}
+                                                </button>
+                                                <button
+                                                    aria-label="delete saved request"
+                                                    on:click=move |_| {
+                                                        saved
+                                                            .update(|requests| {
+                                                                requests.retain(|r| r.name != name);
+                                                                store(requests);
+                                                            })
+                                                    }
+                                                >
+                                                    <Icon icon=i::FaTrashCanSolid />
+                                                </button>
+                                            </li>
+                                        }
+                                    })
+                                    .collect::<Vec<_>>()}
+                            </ul>
+                        }
+                    })
+            }}
+            {move || {
+                response
+                    .get()
+                    .map(|log| {
+                        view! {
+                            <div class="px-4 mt-2 border-2 bg-base-200 border-base-300">
+                                <RequestLogEntry log=log />
+                            </div>
+                        }
+                    })
+            }}
+        </div>
+    }
+}
+
+#[component]
+fn KeyValueEditor(
+    title: &'static str,
+    rows: Signal<Vec<(String, String)>>,
+    set_rows: Callback<Vec<(String, String)>>,
+) -> impl IntoView {
+    let edit = move |index: usize, key: Option<String>, value: Option<String>| {
+        let mut updated = rows.get_untracked();
+        if let Some((k, v)) = updated.get_mut(index) {
+            if let Some(key) = key {
+                *k = key;
+            }
+            if let Some(value) = value {
+                *v = value;
+            }
+        }
+        set_rows.run(updated);
+    };
+
+    view! {
+        <fieldset class="w-full fieldset">
+            <legend class="fieldset-legend">{title}</legend>
+            {move || {
+                rows.get()
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, (key, value))| {
+                        view! {
+                            <div class="flex gap-2">
+                                <input
+                                    type="text"
+                                    class="input input-sm"
+                                    placeholder="name"
+                                    prop:value=key
+                                    on:change=move |ev| edit(index, Some(event_target_value(&ev)), None)
+                                />
+                                <input
+                                    type="text"
+                                    class="input input-sm"
+                                    placeholder="value"
+                                    prop:value=value
+                                    on:change=move |ev| edit(index, None, Some(event_target_value(&ev)))
+                                />
+                                <button
+                                    class="btn btn-sm btn-ghost"
+                                    aria-label="remove row"
+                                    on:click=move |_| {
+                                        let mut updated = rows.get_untracked();
+                                        updated.remove(index);
+                                        set_rows.run(updated);
+                                    }
+                                >
+                                    <Icon icon=i::FaXmarkSolid />
+                                </button>
+                            </div>
+                        }
+                    })
+                    .collect::<Vec<_>>()
+            }}
+            <button
+                class="w-fit btn btn-xs btn-outline"
+                on:click=move |_| {
+                    let mut updated = rows.get_untracked();
+                    updated.push((String::new(), String::new()));
+                    set_rows.run(updated);
+                }
+            >
+                "Add"
+            </button>
+        </fieldset>
+    }
+}
+
+/// How many calls the request inspector keeps around
+const MAX_LOGGED_REQUESTS: usize = 10;
+
+#[component]
+fn RequestInspector(requests: ReadSignal<Vec<RequestLog>>) -> impl IntoView {
+    view! {
+        <details
+            class="mt-2 border-2 collapse collapse-arrow bg-base-200 border-base-300"
+            aria-label="request inspector with details of the latest API calls"
+        >
+            <summary class="font-semibold collapse-title">"Request Inspector"</summary>
+            <div class="text-sm collapse-content">
+                {move || {
+                    let logs = requests.get();
+                    if logs.is_empty() {
+                        return view! { <p>"No requests yet."</p> }.into_any();
+                    }
+                    logs.into_iter()
+                        .map(|log| view! { <RequestLogEntry log=log /> })
+                        .collect::<Vec<_>>()
+                        .into_any()
+                }}
+            </div>
+        </details>
+    }
+}
+
+#[component]
+fn RequestLogEntry(log: RequestLog) -> impl IntoView {
+    let status_class = match log.status {
+        Some(code) if (200..300).contains(&code) => "badge badge-success",
+        Some(_) => "badge badge-warning",
+        None => "badge badge-error",
+    };
+    let status = log
+        .status
+        .map(|code| code.to_string())
+        .unwrap_or_else(|| "no response".to_string());
+
+    view! {
+        <div class="py-2 border-b border-base-300">
+            <p class="font-mono break-all">
+                <span class="mr-2 badge badge-outline">{log.method}</span>
+                {log.url}
+            </p>
+            <p class="mt-1">
+                <span class=status_class>{status}</span>
+                <span class="ml-2">{format!("{:.0} ms", log.elapsed_ms)}</span>
+                <span class="ml-2">{format!("{} bytes", log.payload_bytes)}</span>
+                <span class="ml-2">
+                    "encoding: "
+                    {log.content_encoding.unwrap_or_else(|| "not exposed".to_string())}
+                </span>
+            </p>
+            {(!log.headers.is_empty())
+                .then(|| {
+                    view! {
+                        <table class="mt-1 table table-xs">
+                            <tbody>
+                                {log
+                                    .headers
+                                    .into_iter()
+                                    .map(|(name, value)| {
+                                        view! {
+                                            <tr>
+                                                <td class="font-mono">{name}</td>
+                                                <td class="font-mono break-all">{value}</td>
+                                            </tr>
+                                        }
+                                    })
+                                    .collect::<Vec<_>>()}
+                            </tbody>
+                        </table>
+                    }
+                })}
+            {(!log.body.is_empty())
+                .then(|| {
+                    view! {
+                        <pre class="overflow-x-auto p-2 mt-1 rounded bg-base-100">
+                            <code>{log.body}</code>
+                        </pre>
+                    }
+                })}
         </div>
     }
 }
 
+/// Takes focus off the picked item, daisyUI dropdowns stay open while focused
+fn blur_active_element() {
+    use leptos::wasm_bindgen::JsCast;
+
+    if let Some(element) = document()
+        .active_element()
+        .and_then(|element| element.dyn_into::<web_sys::HtmlElement>().ok())
+    {
+        let _ = element.blur();
+    }
+}
+
 #[component]
 fn LanguageCodeButton(lang: LanguageCode) -> impl IntoView {
     let DictLangSetter(setter) = use_context().expect("DictLangSetter context");
@@ -386,9 +1364,12 @@ fn LanguageCodeButton(lang: LanguageCode) -> impl IntoView {
 
     view! {
         <li>
-            <button onclick="document.activeElement.blur()" on:click=move |_| setter.set(lang)>
+            <button on:click=move |_| {
+                setter.set(lang);
+                blur_active_element();
+            }>
                 <span class=flag_class></span>
-                {lang.name()}
+                <span lang=lang.code()>{lang.name()}</span>
             </button>
         </li>
     }
@@ -400,7 +1381,10 @@ fn GrammaticalTypeButton(g_type: GrammaticalType) -> impl IntoView {
 
     view! {
         <li>
-            <button onclick="document.activeElement.blur()" on:click=move |_| setter.set(g_type)>
+            <button on:click=move |_| {
+                setter.set(g_type);
+                blur_active_element();
+            }>
                 {g_type.name()}
             </button>
         </li>
@@ -409,14 +1393,51 @@ fn GrammaticalTypeButton(g_type: GrammaticalType) -> impl IntoView {
 
 #[component]
 fn Footer() -> impl IntoView {
+    let backends = StoredValue::new(api_urls());
+    let api_version =
+        LocalResource::new(move || async move { get_api_version(&backends.get_value()).await });
+
     view! {
+        {move || {
+            api_version
+                .get()
+                .flatten()
+                .filter(|(_, version)| !is_compatible_api_version(version))
+                .map(|(backend, version)| {
+                    view! {
+                        <div role="alert" class="mx-4 mt-12 alert alert-warning alert-soft">
+                            <Icon icon=i::FaCircleExclamationSolid />
+                            <span>
+                                {format!(
+                                    "{} serves API version {}, this page expects {}. Some features may not work.",
+                                    backends.get_value()[backend],
+                                    version,
+                                    SUPPORTED_API_VERSION,
+                                )}
+                            </span>
+                        </div>
+                    }
+                })
+        }}
         <footer
             class="items-center p-4 mt-12 border-t footer border-base-300 bg-base-100 footer-horizontal"
-            aria-label="footer with copyright info and link to GitHub repository and LinkedIn"
+            aria-label="footer with copyright info, versions and link to GitHub repository and LinkedIn"
         >
             <aside class="grid-flow-col items-center">
                 <Icon icon=i::FaCopyrightRegular />
-                <p>2025 - Andrea C</p>
+                <p>{build_year()} " - Andrea C"</p>
+            </aside>
+            <aside class="text-xs opacity-70 md:place-self-center" aria-label="frontend and API versions">
+                <p>
+                    {format!("v{} ({}, {})", VERSION, GIT_COMMIT, BUILD_DATE)}
+                </p>
+                <p>
+                    {move || match api_version.get() {
+                        Some(Some((_, version))) => format!("API v{}", version.trim_start_matches('v')),
+                        Some(None) => "API version unknown".to_string(),
+                        None => "API version…".to_string(),
+                    }}
+                </p>
             </aside>
             <nav class="grid-flow-col gap-2 text-2xl md:justify-self-end md:place-self-center">
                 <a href=REPO_URL target="_blank" rel="noopener noreferrer">
@@ -434,9 +1455,32 @@ fn Footer() -> impl IntoView {
     }
 }
 
+/// Title, description, OpenGraph and Twitter tags of a page, with the
+/// JSON-LD description of the API
+#[component]
+fn PageMeta(page: &'static Page) -> impl IntoView {
+    view! {
+        <Title text=page.title />
+        <Meta name="description" content=page.description />
+        <Link rel="canonical" href=page.url() />
+        <Meta property="og:type" content="website" />
+        <Meta property="og:site_name" content=HOME.title />
+        <Meta property="og:title" content=page.title />
+        <Meta property="og:description" content=page.description />
+        <Meta property="og:url" content=page.url() />
+        <Meta name="twitter:card" content="summary" />
+        <Meta name="twitter:title" content=page.title />
+        <Meta name="twitter:description" content=page.description />
+        <Script type_="application/ld+json">{json_ld()}</Script>
+    }
+}
+
 #[component]
 pub fn App() -> impl IntoView {
+    provide_meta_context();
+
     view! {
+        <PageMeta page=&HOME />
         <main class="font-sans bg-base-100 text-base-content h-dvh">
             <div class="flex flex-col m-auto max-w-3xl">
                 <div class="text-base md:text-lg">
@@ -448,3 +1492,213 @@ pub fn App() -> impl IntoView {
         </main>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use any_spawner::Executor;
+    use std::sync::Mutex;
+
+    /// Renders `view` to a string in a fresh owner, with `Demo` getting
+    /// its words from `source`. Effects only run when `settle` is set, they
+    /// and the requests they send then run as far as they can. Requests to
+    /// the real backends never complete, they only need a runtime to start.
+    fn render<V: RenderHtml>(source: WordSource, settle: bool, view: impl FnOnce() -> V) -> String {
+        let _ = Executor::init_futures_executor();
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        let _runtime = runtime.enter();
+        Owner::new().with(|| {
+            provide_context(source);
+            let view = view();
+            if settle {
+                Executor::poll_local();
+            }
+            view.to_html()
+        })
+    }
+
+    /// A word source that never answers, for views that don't need one
+    fn pending_source() -> WordSource {
+        WordSource::new(|_, _, _, _| Box::pin(std::future::pending()))
+    }
+
+    type Calls = Arc<Mutex<Vec<(usize, LanguageCode, GrammaticalType)>>>;
+
+    /// A word source answering every request with `result`, and the requests it got
+    fn fake_source(result: Result<Word, ApiError>) -> (WordSource, Calls) {
+        let calls = Calls::default();
+        let source = WordSource::new({
+            let calls = Arc::clone(&calls);
+            move |preferred, lang, word_type, _| {
+                calls.lock().unwrap().push((preferred, lang, word_type));
+                let result = result.clone().map(|word| (0, word));
+                Box::pin(async move { result })
+            }
+        });
+        (source, calls)
+    }
+
+    fn word() -> Word {
+        Word {
+            word: "serendipity".to_string(),
+            definition: "the occurrence of events by chance in a happy way".to_string(),
+            pronunciation: "/ser-en-dip-i-ty/".to_string(),
+            grammatical_type: Some(GrammaticalType::Noun),
+            ..Default::default()
+        }
+    }
+
+    /// The opening tags of every `<a>` element in `html`
+    fn anchors(html: &str) -> Vec<&str> {
+        html.match_indices("<a ")
+            .filter_map(|(start, _)| {
+                html[start..]
+                    .find('>')
+                    .map(|end| &html[start..=start + end])
+            })
+            .collect()
+    }
+
+    #[test]
+    fn demo_shows_a_spinner_while_loading() {
+        let html = render(pending_source(), true, || view! { <Demo /> });
+
+        assert!(html.contains("loading-spinner"));
+        assert!(!html.contains("word: "));
+        assert!(!html.contains("Error: "));
+    }
+
+    #[test]
+    fn backends_are_shown_as_configured_before_mount() {
+        let html = render(pending_source(), false, || view! { <Demo /> });
+        let url = ApiRoute::Random {
+            lang: LanguageCode::English,
+            word_type: GrammaticalType::Random,
+        }
+        .url(&configured_api_urls()[0]);
+        assert!(html.contains(&url), "{}", html);
+
+        let html = render(pending_source(), false, || view! { <Playground /> });
+        for (index, base) in configured_api_urls().iter().enumerate() {
+            let option = format!("<option value=\"{}\"", index);
+            let start = html.find(&option).unwrap();
+            let end = start + html[start..].find("</option>").unwrap();
+            assert!(
+                html[start..end].ends_with(&format!(">{}", base)),
+                "{}",
+                html
+            );
+        }
+    }
+
+    #[test]
+    fn demo_shows_the_loaded_word() {
+        let (source, calls) = fake_source(Ok(word()));
+        let html = render(source, true, || view! { <Demo /> });
+
+        assert_eq!(
+            *calls.lock().unwrap(),
+            [(0, LanguageCode::English, GrammaticalType::Random)]
+        );
+        assert!(html.contains("serendipity"));
+        assert!(html.contains("the occurrence of events by chance in a happy way"));
+        assert!(html.contains("/ser-en-dip-i-ty/"));
+        assert!(html.contains("Noun"));
+        assert!(html.contains(&configured_api_urls()[0]));
+        assert!(!html.contains("loading-spinner"));
+        assert!(!html.contains("Error: "));
+    }
+
+    #[test]
+    fn demo_shows_backend_errors() {
+        let (source, _) = fake_source(Err(ApiError::Status(503)));
+        let html = render(source, true, || view! { <Demo /> });
+
+        assert!(html.contains("text-error"));
+        assert!(html.contains(&ApiError::Status(503).to_string()));
+        assert!(!html.contains("loading-spinner"));
+        assert!(!html.contains("serendipity"));
+    }
+
+    #[test]
+    fn demo_dropdowns_list_every_language_and_type() {
+        let (source, _) = fake_source(Ok(word()));
+        let html = render(source, true, || view! { <Demo /> });
+
+        for lang in LanguageCode::all() {
+            let item = format!("<span lang=\"{}\">{}</span>", lang.code(), lang.name());
+            assert!(html.contains(&item), "missing {}", item);
+            assert!(html.contains(&format!("fi fi-{} fis", lang.flag_code())));
+        }
+        for g_type in GrammaticalType::all() {
+            assert!(html.contains(&format!("<button>{}</button>", g_type.name())));
+        }
+        assert_eq!(
+            html.matches("<li><button>").count(),
+            LanguageCode::all().len() + GrammaticalType::all().len()
+        );
+    }
+
+    #[test]
+    fn landing_links_open_external_pages_safely() {
+        let html = render(pending_source(), false, || view! { <Landing /> });
+        let anchors = anchors(&html);
+
+        assert!(anchors
+            .iter()
+            .any(|a| a.contains(&format!("href=\"{}\"", REPO_URL))));
+        for anchor in anchors.iter().filter(|a| a.contains("target=\"_blank\"")) {
+            assert!(
+                anchor.contains("rel=\"noopener noreferrer\""),
+                "{} opens a new tab without rel=noopener",
+                anchor
+            );
+        }
+
+        let sps = anchors
+            .iter()
+            .find(|a| a.contains(&format!("href=\"{}\"", sps_url())))
+            .expect("Speak and Spell link");
+        assert_eq!(sps.contains("target=\"_blank\""), sps_url() != "/play");
+    }
+
+    #[test]
+    fn navbar_links_home_and_has_a_theme_toggle() {
+        let html = render(pending_source(), false, || view! { <Navbar /> });
+
+        assert!(anchors(&html).iter().any(|a| a.contains("href=\"/\"")));
+        assert!(html.contains("class=\"theme-controller\""));
+    }
+
+    #[test]
+    fn footer_shows_the_build_info() {
+        let html = render(pending_source(), false, || view! { <Footer /> });
+
+        assert!(html.contains(build_year()));
+        assert!(html.contains(&format!("v{} ({}, {})", VERSION, GIT_COMMIT, BUILD_DATE)));
+        assert!(html.contains("API version…"));
+        for anchor in anchors(&html) {
+            assert!(anchor.contains("rel=\"noopener noreferrer\""));
+        }
+    }
+
+    #[test]
+    fn saved_requests_skip_the_ones_that_cant_be_read() {
+        let request = PlaygroundRequest {
+            name: "verbs".to_string(),
+            word_type: GrammaticalType::Verb,
+            ..PlaygroundRequest::default()
+        };
+        let json = serde_json::json!([
+            request,
+            { "name": "from a newer version", "endpoint": "Teleport" },
+        ]);
+
+        assert_eq!(parse_saved_requests(&json.to_string()), Some(vec![request]));
+        assert_eq!(parse_saved_requests("{\"name\": \"verbs\"}"), None);
+        assert_eq!(parse_saved_requests("not json"), None);
+    }
+}
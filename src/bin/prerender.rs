@@ -0,0 +1,38 @@
+//! Build step rendering the landing page into Trunk's `dist/index.html`, so
+//! static hosts like Netlify serve the content before the wasm hydrates it.
+//! It also writes `sitemap.xml` and `robots.txt` for search engines, and a
+//! Netlify `_headers` file whose Content-Security-Policy allows the inline
+//! scripts of the final `index.html`.
+//!
+//! ```sh
+//! trunk build --release --no-default-features --features hydrate
+//! cargo run --release --bin prerender --no-default-features --features ssr
+//! ```
+
+use std::path::Path;
+
+use fe_leptos_landingpage::{
+    prerender::{render_headers, render_index},
+    seo::{robots_txt, sitemap},
+};
+
+fn main() -> std::io::Result<()> {
+    let dist = std::env::var("DIST_DIR").unwrap_or_else(|_| "dist".to_string());
+    let index = Path::new(&dist).join("index.html");
+
+    let html = render_index(&std::fs::read_to_string(&index)?)?;
+    std::fs::write(&index, &html)?;
+
+    println!("prerendered {}", index.display());
+
+    for (name, contents) in [
+        ("sitemap.xml", sitemap()),
+        ("robots.txt", robots_txt()),
+        ("_headers", render_headers(&html)),
+    ] {
+        let path = Path::new(&dist).join(name);
+        std::fs::write(&path, contents)?;
+        println!("wrote {}", path.display());
+    }
+    Ok(())
+}
@@ -0,0 +1,224 @@
+//! Companion server for same-origin deployments.
+//!
+//! Serves the Trunk `dist` output and reverse-proxies `/api` and the OpenAPI
+//! documentation paths to the backend, so the landing page works without CORS.
+//! Build the frontend with `API_URL=/api` and run with:
+//!
+//! ```sh
+//! BACKEND_URL=http://localhost:3000 cargo run --bin server --features server
+//! ```
+//!
+//! With the `ssr` feature as well the landing page is rendered into
+//! `index.html` once at startup, and the frontend (built with `hydrate`)
+//! hydrates it.
+
+use axum::{
+    body::{to_bytes, Body},
+    extract::{Request, State},
+    http::{
+        header::{CACHE_CONTROL, HOST},
+        HeaderValue, StatusCode,
+    },
+    middleware::{self, Next},
+    response::{IntoResponse, Response},
+    routing::any,
+    Router,
+};
+use std::borrow::Cow;
+use std::path::Path;
+#[cfg(not(feature = "ssr"))]
+use tower_http::services::ServeFile;
+use tower_http::{compression::CompressionLayer, services::ServeDir};
+
+/// Largest request body forwarded to the backend
+const MAX_BODY_BYTES: usize = 1024 * 1024;
+
+/// Paths of the OpenAPI tools served by the backend, proxied as they are
+const DOCS_PATHS: &[&str] = &["/swagger-ui", "/scalar", "/redoc", "/rapidoc", "/api-docs"];
+
+/// Headers that only make sense for a single connection
+const HOP_BY_HOP: &[&str] = &[
+    "connection",
+    "keep-alive",
+    "proxy-authenticate",
+    "proxy-authorization",
+    "te",
+    "trailer",
+    "transfer-encoding",
+    "upgrade",
+];
+
+#[derive(Clone)]
+struct Proxy {
+    backend: String,
+    client: reqwest::Client,
+}
+
+fn env_or(name: &str, default: &str) -> String {
+    std::env::var(name).unwrap_or_else(|_| default.to_string())
+}
+
+/// The backend path for a proxied one: `/api` and `/api/...` lose the prefix,
+/// the documentation paths, `/api-docs` among them, are passed on as they are
+fn upstream_path(path_and_query: &str) -> Cow<'_, str> {
+    match path_and_query.strip_prefix("/api") {
+        Some("") => Cow::Borrowed("/"),
+        Some(rest) if rest.starts_with('?') => Cow::Owned(format!("/{}", rest)),
+        Some(rest) if rest.starts_with('/') => Cow::Borrowed(rest),
+        _ => Cow::Borrowed(path_and_query),
+    }
+}
+
+async fn forward(State(proxy): State<Proxy>, request: Request) -> Response {
+    let path_and_query = request
+        .uri()
+        .path_and_query()
+        .map(|pq| pq.as_str())
+        .unwrap_or("/");
+    let url = format!("{}{}", proxy.backend, upstream_path(path_and_query));
+
+    let method = request.method().clone();
+    let headers = request.headers().clone();
+    let body = match to_bytes(request.into_body(), MAX_BODY_BYTES).await {
+        Ok(body) => body,
+        Err(e) => return (StatusCode::PAYLOAD_TOO_LARGE, e.to_string()).into_response(),
+    };
+
+    let upstream = headers
+        .iter()
+        .filter(|(name, _)| *name != HOST && !HOP_BY_HOP.contains(&name.as_str()))
+        .fold(
+            proxy.client.request(method, &url),
+            |upstream, (name, value)| upstream.header(name, value),
+        );
+
+    let response = match upstream.body(body).send().await {
+        Ok(response) => response,
+        Err(e) => return (StatusCode::BAD_GATEWAY, e.to_string()).into_response(),
+    };
+
+    let mut builder = Response::builder().status(response.status());
+    for (name, value) in response.headers() {
+        if !HOP_BY_HOP.contains(&name.as_str()) {
+            builder = builder.header(name, value);
+        }
+    }
+
+    match response.bytes().await {
+        Ok(bytes) => builder
+            .body(Body::from(bytes))
+            .unwrap_or_else(|e| (StatusCode::BAD_GATEWAY, e.to_string()).into_response()),
+        Err(e) => (StatusCode::BAD_GATEWAY, e.to_string()).into_response(),
+    }
+}
+
+/// Trunk hashes wasm, js and css file names, so those can be cached forever.
+/// Everything else, index.html above all, is revalidated on every visit.
+async fn cache_headers(request: Request, next: Next) -> Response {
+    let hashed = Path::new(request.uri().path())
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| matches!(ext, "wasm" | "js" | "css"));
+
+    let mut response = next.run(request).await;
+    let value = if hashed {
+        "public, max-age=31536000, immutable"
+    } else {
+        "no-cache"
+    };
+    response
+        .headers_mut()
+        .entry(CACHE_CONTROL)
+        .or_insert(HeaderValue::from_static(value));
+    response
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let dist = env_or("DIST_DIR", "dist");
+    let addr = env_or("BIND_ADDR", "0.0.0.0:8080");
+    let proxy = Proxy {
+        backend: env_or("BACKEND_URL", "http://localhost:3000")
+            .trim_end_matches('/')
+            .to_string(),
+        client: reqwest::Client::new(),
+    };
+
+    #[cfg(feature = "ssr")]
+    let index = {
+        use axum::{handler::HandlerWithoutStateExt, response::Html};
+
+        let template = std::fs::read_to_string(Path::new(&dist).join("index.html"))?;
+        let html = fe_leptos_landingpage::prerender::render_index(&template)?;
+        (move || {
+            let html = html.clone();
+            async move { Html(html) }
+        })
+        .into_service()
+    };
+    #[cfg(not(feature = "ssr"))]
+    let index = ServeFile::new(Path::new(&dist).join("index.html"));
+
+    let static_files = ServeDir::new(&dist)
+        .append_index_html_on_directories(!cfg!(feature = "ssr"))
+        .fallback(index);
+
+    let mut app = Router::new()
+        .route("/api", any(forward))
+        .route("/api/{*path}", any(forward));
+    for path in DOCS_PATHS {
+        app = app
+            .route(path, any(forward))
+            .route(&format!("{path}/{{*path}}"), any(forward));
+    }
+    let app = app
+        .with_state(proxy)
+        .fallback_service(
+            Router::new()
+                .fallback_service(static_files)
+                .layer(middleware::from_fn(cache_headers)),
+        )
+        .layer(CompressionLayer::new());
+
+    let listener = tokio::net::TcpListener::bind(&addr).await?;
+    println!("serving {} on http://{}", dist, addr);
+    axum::serve(listener, app).await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn api_prefix_is_stripped() {
+        for (path, upstream) in [
+            ("/api", "/"),
+            ("/api/", "/"),
+            ("/api?lang=en", "/?lang=en"),
+            ("/api/en/noun", "/en/noun"),
+            ("/api/en/word/caf%C3%A9?x=1", "/en/word/caf%C3%A9?x=1"),
+            ("/api/health/alive", "/health/alive"),
+            ("/api/api-docs/openapi.json", "/api-docs/openapi.json"),
+        ] {
+            assert_eq!(upstream_path(path), upstream, "{}", path);
+        }
+    }
+
+    #[test]
+    fn docs_paths_are_passed_on() {
+        for path in DOCS_PATHS {
+            for suffix in ["", "/", "/index.html", "/openapi.json?v=1"] {
+                let path = format!("{}{}", path, suffix);
+                assert_eq!(upstream_path(&path), path);
+            }
+        }
+    }
+
+    #[test]
+    fn other_api_lookalikes_are_left_alone() {
+        for path in ["/apiary", "/api.json", "/", "/index.html"] {
+            assert_eq!(upstream_path(path), path);
+        }
+    }
+}
@@ -0,0 +1,211 @@
+//! Contract tests against the OpenAPI document of a running backend.
+//!
+//! They fetch `/api-docs/openapi.json` from the first backend in `API_URL`, so
+//! they're ignored by default. Start the backend, then run:
+//!
+//! ```sh
+//! cargo test contract -- --ignored
+//! ```
+//!
+//! When these fail, the API changed under the frontend.
+
+use crate::api::ApiRoute;
+use crate::model::*;
+use serde_json::Value;
+
+/// Types and languages behind a cargo feature, which the API doesn't serve
+/// yet. Take them out of here when it does, along with their feature.
+const NOT_SERVED_YET: &[&str] = &[
+    "pronoun",
+    "preposition",
+    "conjunction",
+    "interjection",
+    "article",
+    "de",
+    "fr",
+    "es",
+    "it",
+    "nl",
+];
+
+async fn spec() -> Value {
+    let backends = api_urls();
+    let backend = backends.first().expect("a backend in API_URL");
+    let url = format!("{}/api-docs/openapi.json", backend);
+    let response = reqwest::get(&url)
+        .await
+        .unwrap_or_else(|e| panic!("no backend at {}: {}", url, e));
+    assert!(
+        response.status().is_success(),
+        "{}: {}",
+        url,
+        response.status()
+    );
+    response.json().await.unwrap()
+}
+
+/// `schema`, or the schema it refers to
+fn resolve<'a>(spec: &'a Value, schema: &'a Value) -> &'a Value {
+    match schema["$ref"].as_str() {
+        Some(reference) => {
+            let name = reference.trim_start_matches("#/components/schemas/");
+            let schema = &spec["components"]["schemas"][name];
+            assert!(!schema.is_null(), "no {} schema in the spec", name);
+            resolve(spec, schema)
+        }
+        None => schema,
+    }
+}
+
+/// The enum values of `schema`, which must be an enum of strings
+fn values(spec: &Value, schema: &Value) -> Vec<String> {
+    let schema = resolve(spec, schema);
+    let values = schema["enum"]
+        .as_array()
+        .unwrap_or_else(|| panic!("not an enum: {}", schema));
+    values
+        .iter()
+        .map(|value| value.as_str().unwrap().to_string())
+        .collect()
+}
+
+fn operation<'a>(spec: &'a Value, path: &str) -> &'a Value {
+    let operation = &spec["paths"][path]["get"];
+    assert!(!operation.is_null(), "no GET {} in the spec", path);
+    operation
+}
+
+/// The schema of the required path parameter `name` of `operation`
+fn parameter<'a>(operation: &'a Value, name: &str) -> &'a Value {
+    let parameter = operation["parameters"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .find(|parameter| parameter["name"] == name)
+        .unwrap_or_else(|| panic!("no {} parameter", name));
+    assert_eq!(parameter["in"], "path", "{}", name);
+    assert_eq!(parameter["required"], true, "{}", name);
+    &parameter["schema"]
+}
+
+/// Checks that the values `schema` allows are `values`, apart from the ones
+/// the API doesn't serve yet
+fn check_values(spec: &Value, schema: &Value, values: &[&str]) {
+    let allowed = self::values(spec, schema);
+    for value in values {
+        assert_eq!(
+            allowed.contains(&value.to_string()),
+            !NOT_SERVED_YET.contains(value),
+            "{:?}",
+            value
+        );
+    }
+    for value in &allowed {
+        assert!(
+            values.contains(&value.as_str()),
+            "the API serves {:?}, the frontend doesn't know it",
+            value
+        );
+    }
+}
+
+fn type_names() -> Vec<&'static str> {
+    GrammaticalType::all()
+        .iter()
+        .map(GrammaticalType::api_name)
+        .collect()
+}
+
+fn language_codes() -> Vec<&'static str> {
+    LanguageCode::all().iter().map(LanguageCode::code).collect()
+}
+
+/// A word with every field set, as JSON
+fn full_word() -> serde_json::Map<String, Value> {
+    let word = Word {
+        id: Some(1),
+        word: "word".to_string(),
+        definition: "definition".to_string(),
+        pronunciation: "pronunciation".to_string(),
+        grammatical_type: Some(GrammaticalType::Noun),
+        language: Some(LanguageCode::English),
+        syllables: vec!["word".to_string()],
+        examples: vec!["A word.".to_string()],
+        ..Word::default()
+    };
+    match serde_json::to_value(word).unwrap() {
+        Value::Object(fields) => fields,
+        other => panic!("a word serializes to an object, got {}", other),
+    }
+}
+
+#[tokio::test]
+#[ignore = "needs a running backend, see the module docs"]
+async fn word_fields_match_the_schema() {
+    let spec = spec().await;
+    let schema = resolve(&spec, &spec["components"]["schemas"]["Word"]);
+
+    for (name, value) in full_word() {
+        let property = resolve(&spec, &schema["properties"][&name]);
+        assert!(!property.is_null(), "Word.{} isn't in the schema", name);
+
+        match &value {
+            Value::Number(_) => assert_eq!(property["type"], "integer", "{}", name),
+            Value::Array(_) => {
+                assert_eq!(property["type"], "array", "{}", name);
+                assert_eq!(property["items"]["type"], "string", "{}", name);
+            }
+            Value::String(value) if property["enum"].is_array() => assert!(
+                values(&spec, property).contains(value),
+                "Word.{} {:?} isn't one of the allowed values",
+                name,
+                value
+            ),
+            Value::String(_) => assert_eq!(property["type"], "string", "{}", name),
+            other => panic!("unexpected Word.{}: {}", name, other),
+        }
+    }
+}
+
+#[tokio::test]
+#[ignore = "needs a running backend, see the module docs"]
+async fn fields_the_word_needs_are_required_by_the_schema() {
+    let spec = spec().await;
+    let schema = resolve(&spec, &spec["components"]["schemas"]["Word"]);
+    let required = schema["required"].as_array().cloned().unwrap_or_default();
+    let fields = full_word();
+
+    for name in fields.keys() {
+        let mut json = fields.clone();
+        json.remove(name);
+        if serde_json::from_value::<Word>(Value::Object(json)).is_err() {
+            assert!(
+                required.contains(&Value::from(name.as_str())),
+                "Word.{} is needed, the API may leave it out",
+                name
+            );
+        }
+    }
+}
+
+#[tokio::test]
+#[ignore = "needs a running backend, see the module docs"]
+async fn random_route_matches_the_spec() {
+    let spec = spec().await;
+    let operation = operation(&spec, "/{lang}/{type}");
+
+    for lang in LanguageCode::all() {
+        for word_type in GrammaticalType::all() {
+            let path = "/{lang}/{type}"
+                .replace("{lang}", lang.code())
+                .replace("{type}", word_type.api_name());
+            let route = ApiRoute::Random {
+                lang: *lang,
+                word_type: *word_type,
+            };
+            assert_eq!(path, route.path());
+        }
+    }
+    check_values(&spec, parameter(operation, "lang"), &language_codes());
+    check_values(&spec, parameter(operation, "type"), &type_names());
+}
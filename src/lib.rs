@@ -0,0 +1,10 @@
+pub mod api;
+pub mod app;
+#[cfg(test)]
+mod contract;
+pub mod model;
+pub mod openapi;
+#[cfg(feature = "ssr")]
+pub mod prerender;
+pub mod seo;
+pub mod snippets;
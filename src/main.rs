@@ -1,12 +1,15 @@
-mod app;
-mod model;
+// Test builds enable the `ssr` rendering of tachys, a dev-dependency, and
+// its types for `App` nest deeper than the default limit
+#![recursion_limit = "256"]
 
-use app::*;
-use leptos::prelude::*;
+use fe_leptos_landingpage::app::*;
 
 fn main() {
     console_error_panic_hook::set_once();
-    mount_to_body(|| {
-        view! { <App /> }
-    })
+
+    #[cfg(feature = "hydrate")]
+    leptos::mount::hydrate_body(App);
+
+    #[cfg(not(feature = "hydrate"))]
+    leptos::mount::mount_to_body(App);
 }
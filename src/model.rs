@@ -1,10 +1,52 @@
-use serde::{Deserialize, Serialize};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::BTreeMap;
 use std::fmt;
+use std::str::FromStr;
 
 pub const REPO_URL: &str = "https://github.com/andreacfromtheapp/random-word-api";
 
-pub fn api_url() -> String {
-    env!("API_URL").to_string()
+/// This frontend's version, and the commit and date it was built from
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+pub const GIT_COMMIT: &str = env!("GIT_COMMIT");
+pub const BUILD_DATE: &str = env!("BUILD_DATE");
+
+/// The year the frontend was built, for the copyright notice
+pub fn build_year() -> &'static str {
+    BUILD_DATE.split('-').next().unwrap_or(BUILD_DATE)
+}
+
+/// All backends from `API_URL` as written, a comma-separated list in order
+/// of preference
+pub fn configured_api_urls() -> Vec<String> {
+    env!("API_URL")
+        .split(',')
+        .map(|url| url.trim().trim_end_matches('/'))
+        .filter(|url| !url.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// `configured_api_urls()`, with relative entries such as `/api` resolved
+/// against the page's origin
+pub fn api_urls() -> Vec<String> {
+    #[cfg(target_arch = "wasm32")]
+    let origin = web_sys::window()
+        .and_then(|window| window.location().origin().ok())
+        .unwrap_or_default();
+    // Server-side rendering keeps relative entries as they are
+    #[cfg(not(target_arch = "wasm32"))]
+    let origin = String::new();
+
+    configured_api_urls()
+        .into_iter()
+        .map(|url| {
+            if url.starts_with('/') {
+                format!("{}{}", origin, url)
+            } else {
+                url
+            }
+        })
+        .collect()
 }
 
 pub fn sps_url() -> String {
@@ -18,11 +60,16 @@ pub enum GrammaticalType {
     Verb,
     Adjective,
     Adverb,
-    // Pronoun,
-    // Preposition,
-    // Conjunction,
-    // Interjection,
-    // Article,
+    #[cfg(feature = "pronoun")]
+    Pronoun,
+    #[cfg(feature = "preposition")]
+    Preposition,
+    #[cfg(feature = "conjunction")]
+    Conjunction,
+    #[cfg(feature = "interjection")]
+    Interjection,
+    #[cfg(feature = "article")]
+    Article,
 }
 
 impl GrammaticalType {
@@ -33,11 +80,16 @@ impl GrammaticalType {
             GrammaticalType::Verb => "Verb",
             GrammaticalType::Adjective => "Adjective",
             GrammaticalType::Adverb => "Adverb",
-            // GrammaticalType::Pronoun => "Pronoun",
-            // GrammaticalType::Preposition => "Preposition",
-            // GrammaticalType::Conjunction => "Conjunction",
-            // GrammaticalType::Interjection => "Interjection",
-            // GrammaticalType::Article => "Article",
+            #[cfg(feature = "pronoun")]
+            GrammaticalType::Pronoun => "Pronoun",
+            #[cfg(feature = "preposition")]
+            GrammaticalType::Preposition => "Preposition",
+            #[cfg(feature = "conjunction")]
+            GrammaticalType::Conjunction => "Conjunction",
+            #[cfg(feature = "interjection")]
+            GrammaticalType::Interjection => "Interjection",
+            #[cfg(feature = "article")]
+            GrammaticalType::Article => "Article",
         }
     }
 
@@ -48,11 +100,37 @@ impl GrammaticalType {
             GrammaticalType::Verb => "verb",
             GrammaticalType::Adjective => "adjective",
             GrammaticalType::Adverb => "adverb",
-            // GrammaticalType::Pronoun => "pronoun",
-            // GrammaticalType::Preposition => "preposition",
-            // GrammaticalType::Conjunction => "conjunction",
-            // GrammaticalType::Interjection => "interjection",
-            // GrammaticalType::Article => "article",
+            #[cfg(feature = "pronoun")]
+            GrammaticalType::Pronoun => "pronoun",
+            #[cfg(feature = "preposition")]
+            GrammaticalType::Preposition => "preposition",
+            #[cfg(feature = "conjunction")]
+            GrammaticalType::Conjunction => "conjunction",
+            #[cfg(feature = "interjection")]
+            GrammaticalType::Interjection => "interjection",
+            #[cfg(feature = "article")]
+            GrammaticalType::Article => "article",
+        }
+    }
+
+    /// Alternative spellings accepted when parsing, besides `api_name()` and `name()`
+    pub fn aliases(&self) -> &'static [&'static str] {
+        match self {
+            GrammaticalType::Random => &["any", "rand"],
+            GrammaticalType::Noun => &["n"],
+            GrammaticalType::Verb => &["v"],
+            GrammaticalType::Adjective => &["adj"],
+            GrammaticalType::Adverb => &["adv"],
+            #[cfg(feature = "pronoun")]
+            GrammaticalType::Pronoun => &["pron"],
+            #[cfg(feature = "preposition")]
+            GrammaticalType::Preposition => &["prep"],
+            #[cfg(feature = "conjunction")]
+            GrammaticalType::Conjunction => &["conj"],
+            #[cfg(feature = "interjection")]
+            GrammaticalType::Interjection => &["interj", "intj"],
+            #[cfg(feature = "article")]
+            GrammaticalType::Article => &["art"],
         }
     }
 
@@ -63,11 +141,16 @@ impl GrammaticalType {
             GrammaticalType::Verb,
             GrammaticalType::Adjective,
             GrammaticalType::Adverb,
-            // GrammaticalType::Pronoun,
-            // GrammaticalType::Preposition,
-            // GrammaticalType::Conjunction,
-            // GrammaticalType::Interjection,
-            // GrammaticalType::Article,
+            #[cfg(feature = "pronoun")]
+            GrammaticalType::Pronoun,
+            #[cfg(feature = "preposition")]
+            GrammaticalType::Preposition,
+            #[cfg(feature = "conjunction")]
+            GrammaticalType::Conjunction,
+            #[cfg(feature = "interjection")]
+            GrammaticalType::Interjection,
+            #[cfg(feature = "article")]
+            GrammaticalType::Article,
         ]
     }
 }
@@ -78,58 +161,158 @@ impl fmt::Display for GrammaticalType {
     }
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseGrammaticalTypeError(String);
+
+impl fmt::Display for ParseGrammaticalTypeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let expected: Vec<_> = GrammaticalType::all()
+            .iter()
+            .map(|t| t.api_name())
+            .collect();
+        write!(
+            f,
+            "unknown grammatical type '{}', expected one of: {}",
+            self.0,
+            expected.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for ParseGrammaticalTypeError {}
+
+impl FromStr for GrammaticalType {
+    type Err = ParseGrammaticalTypeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let needle = s.trim().to_lowercase();
+        GrammaticalType::all()
+            .iter()
+            .find(|t| {
+                t.api_name() == needle
+                    || t.name().to_lowercase() == needle
+                    || t.aliases().contains(&needle.as_str())
+            })
+            .copied()
+            .ok_or_else(|| ParseGrammaticalTypeError(s.to_string()))
+    }
+}
+
+impl TryFrom<&str> for GrammaticalType {
+    type Error = ParseGrammaticalTypeError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl Serialize for GrammaticalType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.api_name())
+    }
+}
+
+impl<'de> Deserialize<'de> for GrammaticalType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(de::Error::custom)
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum LanguageCode {
     English,
-    // German,
-    // French,
-    // Spanish,
-    // Italian,
-    // Dutch,
+    #[cfg(feature = "german")]
+    German,
+    #[cfg(feature = "french")]
+    French,
+    #[cfg(feature = "spanish")]
+    Spanish,
+    #[cfg(feature = "italian")]
+    Italian,
+    #[cfg(feature = "dutch")]
+    Dutch,
 }
 
 impl LanguageCode {
     pub fn code(&self) -> &'static str {
         match self {
             LanguageCode::English => "en",
-            // LanguageCode::German => "de",
-            // LanguageCode::French => "fr",
-            // LanguageCode::Spanish => "es",
-            // LanguageCode::Italian => "it",
-            // LanguageCode::Dutch => "nl",
+            #[cfg(feature = "german")]
+            LanguageCode::German => "de",
+            #[cfg(feature = "french")]
+            LanguageCode::French => "fr",
+            #[cfg(feature = "spanish")]
+            LanguageCode::Spanish => "es",
+            #[cfg(feature = "italian")]
+            LanguageCode::Italian => "it",
+            #[cfg(feature = "dutch")]
+            LanguageCode::Dutch => "nl",
         }
     }
 
     pub fn name(&self) -> &'static str {
         match self {
             LanguageCode::English => "English",
-            // LanguageCode::German => "Deutsch",
-            // LanguageCode::French => "Francais",
-            // LanguageCode::Spanish => "Espanol",
-            // LanguageCode::Italian => "Italiano",
-            // LanguageCode::Dutch => "Dutch",
+            #[cfg(feature = "german")]
+            LanguageCode::German => "Deutsch",
+            #[cfg(feature = "french")]
+            LanguageCode::French => "Français",
+            #[cfg(feature = "spanish")]
+            LanguageCode::Spanish => "Español",
+            #[cfg(feature = "italian")]
+            LanguageCode::Italian => "Italiano",
+            #[cfg(feature = "dutch")]
+            LanguageCode::Dutch => "Nederlands",
         }
     }
 
     pub fn flag_code(&self) -> &'static str {
         match self {
             LanguageCode::English => "us",
-            // LanguageCode::German => "de",
-            // LanguageCode::French => "fr",
-            // LanguageCode::Spanish => "es",
-            // LanguageCode::Italian => "it",
-            // LanguageCode::Dutch => "nl",
+            #[cfg(feature = "german")]
+            LanguageCode::German => "de",
+            #[cfg(feature = "french")]
+            LanguageCode::French => "fr",
+            #[cfg(feature = "spanish")]
+            LanguageCode::Spanish => "es",
+            #[cfg(feature = "italian")]
+            LanguageCode::Italian => "it",
+            #[cfg(feature = "dutch")]
+            LanguageCode::Dutch => "nl",
+        }
+    }
+
+    /// Alternative spellings accepted when parsing, besides `code()` and `name()`
+    pub fn aliases(&self) -> &'static [&'static str] {
+        match self {
+            LanguageCode::English => &["eng", "en-us", "en-gb"],
+            #[cfg(feature = "german")]
+            LanguageCode::German => &["german", "deu", "ger"],
+            #[cfg(feature = "french")]
+            LanguageCode::French => &["french", "francais", "fra", "fre"],
+            #[cfg(feature = "spanish")]
+            LanguageCode::Spanish => &["spanish", "espanol", "spa"],
+            #[cfg(feature = "italian")]
+            LanguageCode::Italian => &["italian", "ita"],
+            #[cfg(feature = "dutch")]
+            LanguageCode::Dutch => &["dutch", "nld", "dut"],
         }
     }
 
     pub fn all() -> &'static [LanguageCode] {
         &[
             LanguageCode::English,
-            // LanguageCode::German,
-            // LanguageCode::French,
-            // LanguageCode::Spanish,
-            // LanguageCode::Italian,
-            // LanguageCode::Dutch,
+            #[cfg(feature = "german")]
+            LanguageCode::German,
+            #[cfg(feature = "french")]
+            LanguageCode::French,
+            #[cfg(feature = "spanish")]
+            LanguageCode::Spanish,
+            #[cfg(feature = "italian")]
+            LanguageCode::Italian,
+            #[cfg(feature = "dutch")]
+            LanguageCode::Dutch,
         ]
     }
 }
@@ -140,9 +323,586 @@ impl fmt::Display for LanguageCode {
     }
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseLanguageCodeError(String);
+
+impl fmt::Display for ParseLanguageCodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let expected: Vec<_> = LanguageCode::all().iter().map(|l| l.code()).collect();
+        write!(
+            f,
+            "unknown language '{}', expected one of: {}",
+            self.0,
+            expected.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for ParseLanguageCodeError {}
+
+impl FromStr for LanguageCode {
+    type Err = ParseLanguageCodeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let needle = s.trim().to_lowercase();
+        LanguageCode::all()
+            .iter()
+            .find(|l| {
+                l.code() == needle
+                    || l.name().to_lowercase() == needle
+                    || l.aliases().contains(&needle.as_str())
+            })
+            .copied()
+            .ok_or_else(|| ParseLanguageCodeError(s.to_string()))
+    }
+}
+
+impl TryFrom<&str> for LanguageCode {
+    type Error = ParseLanguageCodeError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl Serialize for LanguageCode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.code())
+    }
+}
+
+impl<'de> Deserialize<'de> for LanguageCode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(de::Error::custom)
+    }
+}
+
+/// A dictionary entry as returned by the API.
+///
+/// Fields the frontend doesn't know about are kept in `extras` rather than
+/// dropped, so a newer backend can be inspected without a frontend release.
+/// The same goes for a type or language this build doesn't know, e.g. one
+/// behind a cargo feature that's off: it's left as `None` and its raw value
+/// is kept in `extras` under `grammatical_type` or `language`.
 #[derive(Default, Deserialize, Serialize, Clone)]
+#[serde(from = "RawWord")]
 pub struct Word {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u32>,
     pub word: String,
     pub definition: String,
     pub pronunciation: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub grammatical_type: Option<GrammaticalType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<LanguageCode>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub syllables: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub examples: Vec<String>,
+    #[serde(flatten)]
+    pub extras: BTreeMap<String, serde_json::Value>,
+}
+
+/// `Word` as it comes over the wire, before the type and language are parsed
+#[derive(Deserialize)]
+struct RawWord {
+    #[serde(default)]
+    id: Option<u32>,
+    word: String,
+    definition: String,
+    pronunciation: String,
+    #[serde(default)]
+    grammatical_type: Option<serde_json::Value>,
+    #[serde(default)]
+    language: Option<serde_json::Value>,
+    #[serde(default)]
+    syllables: Vec<String>,
+    #[serde(default)]
+    examples: Vec<String>,
+    #[serde(flatten)]
+    extras: BTreeMap<String, serde_json::Value>,
+}
+
+/// Parses `value` into `T`, or keeps it in `extras` under `key` if this
+/// build doesn't know it
+fn parse_or_keep<T: FromStr>(
+    value: Option<serde_json::Value>,
+    key: &str,
+    extras: &mut BTreeMap<String, serde_json::Value>,
+) -> Option<T> {
+    let value = value.filter(|value| !value.is_null())?;
+    let parsed = value.as_str().and_then(|s| s.parse().ok());
+    if parsed.is_none() {
+        extras.insert(key.to_string(), value);
+    }
+    parsed
+}
+
+impl From<RawWord> for Word {
+    fn from(raw: RawWord) -> Self {
+        let mut extras = raw.extras;
+        let grammatical_type = parse_or_keep(raw.grammatical_type, "grammatical_type", &mut extras);
+        let language = parse_or_keep(raw.language, "language", &mut extras);
+
+        Word {
+            id: raw.id,
+            word: raw.word,
+            definition: raw.definition,
+            pronunciation: raw.pronunciation,
+            grammatical_type,
+            language,
+            syllables: raw.syllables,
+            examples: raw.examples,
+            extras,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use proptest::sample::select;
+    use serde_json::json;
+
+    fn fixture(name: &str) -> serde_json::Value {
+        let path = format!("{}/tests/fixtures/{}", env!("CARGO_MANIFEST_DIR"), name);
+        let json = std::fs::read_to_string(&path).unwrap_or_else(|e| panic!("{}: {}", path, e));
+        serde_json::from_str(&json).unwrap()
+    }
+
+    /// `s` with the characters picked by `mask` uppercased
+    fn mixed_case(s: &str, mask: &[bool]) -> String {
+        s.chars()
+            .zip(mask.iter().chain(std::iter::repeat(&false)))
+            .map(|(c, upper)| {
+                if *upper {
+                    c.to_uppercase().next().unwrap_or(c)
+                } else {
+                    c
+                }
+            })
+            .collect()
+    }
+
+    /// Every spelling `FromStr` should accept for a grammatical type
+    fn type_spellings(g_type: GrammaticalType) -> Vec<String> {
+        [g_type.api_name(), g_type.name()]
+            .iter()
+            .chain(g_type.aliases())
+            .map(|s| s.to_string())
+            .collect()
+    }
+
+    /// Every spelling `FromStr` should accept for a language
+    fn language_spellings(lang: LanguageCode) -> Vec<String> {
+        [lang.code(), lang.name()]
+            .iter()
+            .chain(lang.aliases())
+            .map(|s| s.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn display_is_the_api_name() {
+        for g_type in GrammaticalType::all() {
+            assert_eq!(g_type.to_string(), g_type.api_name());
+        }
+        for lang in LanguageCode::all() {
+            assert_eq!(lang.to_string(), lang.code());
+        }
+    }
+
+    /// No spelling may belong to two variants, or parsing would depend on order
+    fn assert_disjoint(spellings: Vec<Vec<String>>) {
+        let mut seen = std::collections::HashSet::new();
+        for variant in spellings {
+            let variant: std::collections::HashSet<_> = variant
+                .iter()
+                .map(|spelling| spelling.to_lowercase())
+                .collect();
+            for spelling in variant {
+                assert!(
+                    seen.insert(spelling.clone()),
+                    "'{}' is used twice",
+                    spelling
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn spellings_are_unambiguous() {
+        assert_disjoint(
+            GrammaticalType::all()
+                .iter()
+                .map(|t| type_spellings(*t))
+                .collect(),
+        );
+        assert_disjoint(
+            LanguageCode::all()
+                .iter()
+                .map(|l| language_spellings(*l))
+                .collect(),
+        );
+    }
+
+    #[test]
+    fn parse_accepts_every_spelling() {
+        for g_type in GrammaticalType::all() {
+            for spelling in type_spellings(*g_type) {
+                assert_eq!(spelling.parse(), Ok(*g_type));
+                assert_eq!(spelling.to_uppercase().parse(), Ok(*g_type));
+                assert_eq!(format!("  {}\t", spelling).parse(), Ok(*g_type));
+                assert_eq!(GrammaticalType::try_from(spelling.as_str()), Ok(*g_type));
+            }
+        }
+        for lang in LanguageCode::all() {
+            for spelling in language_spellings(*lang) {
+                assert_eq!(spelling.parse(), Ok(*lang));
+                assert_eq!(spelling.to_uppercase().parse(), Ok(*lang));
+                assert_eq!(format!("  {}\t", spelling).parse(), Ok(*lang));
+                assert_eq!(LanguageCode::try_from(spelling.as_str()), Ok(*lang));
+            }
+        }
+    }
+
+    #[test]
+    fn parse_errors_list_the_expected_values() {
+        let error = "gerund".parse::<GrammaticalType>().unwrap_err();
+        let message = error.to_string();
+        assert!(message.starts_with("unknown grammatical type 'gerund', expected one of: "));
+        for g_type in GrammaticalType::all() {
+            assert!(message.contains(g_type.api_name()));
+        }
+
+        let error = "tlh".parse::<LanguageCode>().unwrap_err();
+        let message = error.to_string();
+        assert!(message.starts_with("unknown language 'tlh', expected one of: "));
+        for lang in LanguageCode::all() {
+            assert!(message.contains(lang.code()));
+        }
+
+        assert!("".parse::<GrammaticalType>().is_err());
+        assert!("".parse::<LanguageCode>().is_err());
+    }
+
+    #[test]
+    fn enums_serialize_as_api_names() {
+        for g_type in GrammaticalType::all() {
+            let json = serde_json::to_value(g_type).unwrap();
+            assert_eq!(json, json!(g_type.api_name()));
+            assert_eq!(
+                serde_json::from_value::<GrammaticalType>(json).unwrap(),
+                *g_type
+            );
+        }
+        for lang in LanguageCode::all() {
+            let json = serde_json::to_value(lang).unwrap();
+            assert_eq!(json, json!(lang.code()));
+            assert_eq!(serde_json::from_value::<LanguageCode>(json).unwrap(), *lang);
+        }
+
+        assert!(serde_json::from_value::<GrammaticalType>(json!("gerund")).is_err());
+        assert!(serde_json::from_value::<LanguageCode>(json!(42)).is_err());
+    }
+
+    #[test]
+    fn minimal_word_round_trips() {
+        let json = fixture("word_minimal.json");
+        let word: Word = serde_json::from_value(json.clone()).unwrap();
+
+        assert_eq!(word.id, None);
+        assert_eq!(word.word, "serendipity");
+        assert_eq!(word.grammatical_type, None);
+        assert_eq!(word.language, None);
+        assert!(word.syllables.is_empty());
+        assert!(word.examples.is_empty());
+        assert!(word.extras.is_empty());
+        assert_eq!(serde_json::to_value(&word).unwrap(), json);
+    }
+
+    #[test]
+    fn full_word_round_trips() {
+        let json = fixture("word_full.json");
+        let word: Word = serde_json::from_value(json.clone()).unwrap();
+
+        assert_eq!(word.id, Some(42));
+        assert_eq!(word.grammatical_type, Some(GrammaticalType::Noun));
+        assert_eq!(word.language, Some(LanguageCode::English));
+        assert_eq!(word.syllables, ["ser", "en", "dip", "i", "ty"]);
+        assert_eq!(word.examples.len(), 1);
+        assert_eq!(word.extras.len(), 2);
+        assert_eq!(word.extras["frequency"], json!(0.42));
+        assert_eq!(serde_json::to_value(&word).unwrap(), json);
+    }
+
+    #[test]
+    fn word_without_required_fields_is_rejected() {
+        assert!(serde_json::from_value::<Word>(json!({ "word": "orphan" })).is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn grammatical_type_display_round_trips(g_type in select(GrammaticalType::all())) {
+            prop_assert_eq!(g_type.to_string().parse::<GrammaticalType>(), Ok(g_type));
+        }
+
+        #[test]
+        fn language_display_round_trips(lang in select(LanguageCode::all())) {
+            prop_assert_eq!(lang.to_string().parse::<LanguageCode>(), Ok(lang));
+        }
+
+        #[test]
+        fn grammatical_type_parse_ignores_case(
+            g_type in select(GrammaticalType::all()),
+            index in any::<prop::sample::Index>(),
+            mask in prop::collection::vec(any::<bool>(), 0..16),
+        ) {
+            let spellings = type_spellings(g_type);
+            let spelling = mixed_case(index.get(&spellings), &mask);
+            prop_assert_eq!(spelling.parse::<GrammaticalType>(), Ok(g_type));
+        }
+
+        #[test]
+        fn language_parse_ignores_case(
+            lang in select(LanguageCode::all()),
+            index in any::<prop::sample::Index>(),
+            mask in prop::collection::vec(any::<bool>(), 0..16),
+        ) {
+            let spellings = language_spellings(lang);
+            let spelling = mixed_case(index.get(&spellings), &mask);
+            prop_assert_eq!(spelling.parse::<LanguageCode>(), Ok(lang));
+        }
+
+        #[test]
+        fn parse_only_accepts_known_spellings(input in "\\PC{0,12}") {
+            let needle = input.trim().to_lowercase();
+            match input.parse::<GrammaticalType>() {
+                Ok(g_type) => prop_assert!(type_spellings(g_type)
+                    .iter()
+                    .any(|spelling| spelling.to_lowercase() == needle)),
+                Err(error) => prop_assert!(error.to_string().contains(&input)),
+            }
+            match input.parse::<LanguageCode>() {
+                Ok(lang) => prop_assert!(language_spellings(lang)
+                    .iter()
+                    .any(|spelling| spelling.to_lowercase() == needle)),
+                Err(error) => prop_assert!(error.to_string().contains(&input)),
+            }
+        }
+    }
+
+    /// A minimal word as the backend returns it, with `extra` merged in
+    fn word_fixture(extra: serde_json::Value) -> serde_json::Value {
+        let mut word = json!({
+            "word": "example",
+            "definition": "something that shows what others are like",
+            "pronunciation": "/ɪɡˈzæmpəl/",
+        });
+        if let (Some(word), Some(extra)) = (word.as_object_mut(), extra.as_object()) {
+            word.extend(extra.clone());
+        }
+        word
+    }
+
+    fn check_type(g_type: GrammaticalType, name: &str, api_name: &str, aliases: &[&str]) {
+        assert_eq!(g_type.name(), name);
+        assert_eq!(g_type.api_name(), api_name);
+        assert_eq!(g_type.aliases(), aliases);
+        assert!(GrammaticalType::all().contains(&g_type));
+        assert_eq!(api_name.parse(), Ok(g_type));
+
+        let word: Word =
+            serde_json::from_value(word_fixture(json!({ "grammatical_type": api_name }))).unwrap();
+        assert_eq!(word.grammatical_type, Some(g_type));
+    }
+
+    #[test]
+    fn default_types() {
+        check_type(
+            GrammaticalType::Random,
+            "Random",
+            "random",
+            &["any", "rand"],
+        );
+        check_type(GrammaticalType::Noun, "Noun", "noun", &["n"]);
+        check_type(GrammaticalType::Verb, "Verb", "verb", &["v"]);
+        check_type(
+            GrammaticalType::Adjective,
+            "Adjective",
+            "adjective",
+            &["adj"],
+        );
+        check_type(GrammaticalType::Adverb, "Adverb", "adverb", &["adv"]);
+    }
+
+    #[cfg(feature = "pronoun")]
+    #[test]
+    fn pronoun() {
+        check_type(GrammaticalType::Pronoun, "Pronoun", "pronoun", &["pron"]);
+    }
+
+    #[cfg(feature = "preposition")]
+    #[test]
+    fn preposition() {
+        check_type(
+            GrammaticalType::Preposition,
+            "Preposition",
+            "preposition",
+            &["prep"],
+        );
+    }
+
+    #[cfg(feature = "conjunction")]
+    #[test]
+    fn conjunction() {
+        check_type(
+            GrammaticalType::Conjunction,
+            "Conjunction",
+            "conjunction",
+            &["conj"],
+        );
+    }
+
+    #[cfg(feature = "interjection")]
+    #[test]
+    fn interjection() {
+        check_type(
+            GrammaticalType::Interjection,
+            "Interjection",
+            "interjection",
+            &["interj", "intj"],
+        );
+    }
+
+    #[cfg(feature = "article")]
+    #[test]
+    fn article() {
+        check_type(GrammaticalType::Article, "Article", "article", &["art"]);
+    }
+
+    fn check_language(lang: LanguageCode, code: &str, name: &str, flag: &str, aliases: &[&str]) {
+        assert_eq!(lang.code(), code);
+        assert_eq!(lang.name(), name);
+        assert_eq!(lang.flag_code(), flag);
+        assert_eq!(lang.aliases(), aliases);
+        assert!(LanguageCode::all().contains(&lang));
+        assert_eq!(code.parse(), Ok(lang));
+
+        let word: Word = serde_json::from_value(word_fixture(json!({ "language": code }))).unwrap();
+        assert_eq!(word.language, Some(lang));
+    }
+
+    #[test]
+    fn english() {
+        check_language(
+            LanguageCode::English,
+            "en",
+            "English",
+            "us",
+            &["eng", "en-us", "en-gb"],
+        );
+    }
+
+    #[cfg(feature = "german")]
+    #[test]
+    fn german() {
+        check_language(
+            LanguageCode::German,
+            "de",
+            "Deutsch",
+            "de",
+            &["german", "deu", "ger"],
+        );
+    }
+
+    #[cfg(feature = "french")]
+    #[test]
+    fn french() {
+        check_language(
+            LanguageCode::French,
+            "fr",
+            "Français",
+            "fr",
+            &["french", "francais", "fra", "fre"],
+        );
+    }
+
+    #[cfg(feature = "spanish")]
+    #[test]
+    fn spanish() {
+        check_language(
+            LanguageCode::Spanish,
+            "es",
+            "Español",
+            "es",
+            &["spanish", "espanol", "spa"],
+        );
+    }
+
+    #[cfg(feature = "italian")]
+    #[test]
+    fn italian() {
+        check_language(
+            LanguageCode::Italian,
+            "it",
+            "Italiano",
+            "it",
+            &["italian", "ita"],
+        );
+    }
+
+    #[cfg(feature = "dutch")]
+    #[test]
+    fn dutch() {
+        check_language(
+            LanguageCode::Dutch,
+            "nl",
+            "Nederlands",
+            "nl",
+            &["dutch", "nld", "dut"],
+        );
+    }
+
+    #[test]
+    fn unknown_type_and_language_are_kept_in_extras() {
+        let json = word_fixture(json!({ "grammatical_type": "gerund", "language": "tlh" }));
+        let word: Word = serde_json::from_value(json).unwrap();
+
+        assert_eq!(word.grammatical_type, None);
+        assert_eq!(word.language, None);
+        assert_eq!(word.extras["grammatical_type"], json!("gerund"));
+        assert_eq!(word.extras["language"], json!("tlh"));
+
+        // and survive a round-trip
+        let json = serde_json::to_value(&word).unwrap();
+        assert_eq!(json["grammatical_type"], json!("gerund"));
+        assert_eq!(json["language"], json!("tlh"));
+    }
+
+    #[test]
+    fn unknown_type_does_not_fail_a_word_list() {
+        let json = json!([
+            word_fixture(json!({ "grammatical_type": "noun" })),
+            word_fixture(json!({ "grammatical_type": "gerund" })),
+        ]);
+        let words: Vec<Word> = serde_json::from_value(json).unwrap();
+
+        assert_eq!(words.len(), 2);
+        assert_eq!(words[0].grammatical_type, Some(GrammaticalType::Noun));
+        assert_eq!(words[1].grammatical_type, None);
+    }
+
+    #[cfg(not(feature = "pronoun"))]
+    #[test]
+    fn gated_type_without_its_feature_is_kept_in_extras() {
+        let word: Word =
+            serde_json::from_value(word_fixture(json!({ "grammatical_type": "pronoun" }))).unwrap();
+
+        assert_eq!(word.grammatical_type, None);
+        assert_eq!(word.extras["grammatical_type"], json!("pronoun"));
+    }
 }
@@ -0,0 +1,368 @@
+use serde_json::Value;
+
+use crate::api::encode_segment;
+
+/// The parts of the backend's OpenAPI document the reference renders.
+///
+/// Parsed leniently from raw JSON, anything missing is left empty rather than
+/// failing the whole document.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ApiSpec {
+    pub title: String,
+    pub version: String,
+    pub operations: Vec<Operation>,
+    pub schemas: Vec<Schema>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Operation {
+    pub method: String,
+    pub path: String,
+    pub summary: String,
+    pub description: String,
+    pub tags: Vec<String>,
+    pub parameters: Vec<Parameter>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Parameter {
+    pub name: String,
+    /// Where it goes: `path`, `query`, `header` or `cookie`
+    pub location: String,
+    pub required: bool,
+    pub type_name: String,
+    pub description: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Schema {
+    pub name: String,
+    pub description: String,
+    pub properties: Vec<Property>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Property {
+    pub name: String,
+    pub type_name: String,
+    pub required: bool,
+    pub description: String,
+}
+
+const METHODS: &[&str] = &["get", "post", "put", "patch", "delete", "head", "options"];
+
+fn text(value: &Value, key: &str) -> String {
+    value[key].as_str().unwrap_or_default().to_string()
+}
+
+/// A short type label such as `string`, `Word` or `array<Word>`
+fn type_name(schema: &Value) -> String {
+    if let Some(reference) = schema["$ref"].as_str() {
+        return reference
+            .rsplit('/')
+            .next()
+            .unwrap_or(reference)
+            .to_string();
+    }
+    match schema["type"].as_str() {
+        Some("array") => format!("array<{}>", type_name(&schema["items"])),
+        Some(name) => name.to_string(),
+        None => "object".to_string(),
+    }
+}
+
+impl ApiSpec {
+    pub fn from_json(json: &Value) -> Self {
+        let operations = json["paths"]
+            .as_object()
+            .into_iter()
+            .flatten()
+            .flat_map(|(path, item)| {
+                METHODS.iter().filter_map(move |&method| {
+                    let operation = item.get(method)?;
+                    let parameters = item["parameters"]
+                        .as_array()
+                        .into_iter()
+                        .chain(operation["parameters"].as_array())
+                        .flatten()
+                        .map(|parameter| Parameter {
+                            name: text(parameter, "name"),
+                            location: text(parameter, "in"),
+                            required: parameter["required"].as_bool().unwrap_or(false),
+                            type_name: type_name(&parameter["schema"]),
+                            description: text(parameter, "description"),
+                        })
+                        .collect();
+
+                    Some(Operation {
+                        method: method.to_uppercase(),
+                        path: path.clone(),
+                        summary: text(operation, "summary"),
+                        description: text(operation, "description"),
+                        tags: operation["tags"]
+                            .as_array()
+                            .into_iter()
+                            .flatten()
+                            .filter_map(|tag| tag.as_str().map(str::to_string))
+                            .collect(),
+                        parameters,
+                    })
+                })
+            })
+            .collect();
+
+        let schemas = json["components"]["schemas"]
+            .as_object()
+            .into_iter()
+            .flatten()
+            .map(|(name, schema)| {
+                let required: Vec<_> = schema["required"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .filter_map(Value::as_str)
+                    .collect();
+
+                Schema {
+                    name: name.clone(),
+                    description: text(schema, "description"),
+                    properties: schema["properties"]
+                        .as_object()
+                        .into_iter()
+                        .flatten()
+                        .map(|(property, definition)| Property {
+                            name: property.clone(),
+                            type_name: type_name(definition),
+                            required: required.contains(&property.as_str()),
+                            description: text(definition, "description"),
+                        })
+                        .collect(),
+                }
+            })
+            .collect();
+
+        Self {
+            title: text(&json["info"], "title"),
+            version: text(&json["info"], "version"),
+            operations,
+            schemas,
+        }
+    }
+}
+
+impl Operation {
+    /// Whether the search box text appears in the path, summary, description
+    /// or tags, ignoring case
+    pub fn matches(&self, needle: &str) -> bool {
+        let needle = needle.trim().to_lowercase();
+        needle.is_empty()
+            || self.path.to_lowercase().contains(&needle)
+            || self.summary.to_lowercase().contains(&needle)
+            || self.description.to_lowercase().contains(&needle)
+            || self
+                .tags
+                .iter()
+                .any(|tag| tag.to_lowercase().contains(&needle))
+    }
+
+    /// The request URL with `{param}` path templates replaced and query
+    /// parameters appended, all percent-encoded. `values` is keyed by
+    /// parameter name, empty query values are left out.
+    pub fn url(&self, base: &str, values: &[(String, String)]) -> String {
+        let value = |name: &str| {
+            values
+                .iter()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value.as_str())
+                .unwrap_or_default()
+        };
+
+        let path = self
+            .parameters
+            .iter()
+            .filter(|p| p.location == "path")
+            .fold(self.path.clone(), |path, p| {
+                path.replace(&format!("{{{}}}", p.name), &encode_segment(value(&p.name)))
+            });
+        let query: Vec<_> = self
+            .parameters
+            .iter()
+            .filter(|p| p.location == "query" && !value(&p.name).is_empty())
+            .map(|p| {
+                format!(
+                    "{}={}",
+                    encode_segment(&p.name),
+                    encode_segment(value(&p.name))
+                )
+            })
+            .collect();
+
+        let url = format!("{}{}", base.trim_end_matches('/'), path);
+        if query.is_empty() {
+            url
+        } else {
+            format!("{}?{}", url, query.join("&"))
+        }
+    }
+
+    /// Header parameters with a value, to send along with `url()`
+    pub fn headers(&self, values: &[(String, String)]) -> Vec<(String, String)> {
+        values
+            .iter()
+            .filter(|(name, value)| {
+                !value.is_empty()
+                    && self
+                        .parameters
+                        .iter()
+                        .any(|p| p.location == "header" && &p.name == name)
+            })
+            .cloned()
+            .collect()
+    }
+}
+
+impl Schema {
+    pub fn matches(&self, needle: &str) -> bool {
+        let needle = needle.trim().to_lowercase();
+        needle.is_empty()
+            || self.name.to_lowercase().contains(&needle)
+            || self
+                .properties
+                .iter()
+                .any(|property| property.name.to_lowercase().contains(&needle))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    /// `GET /{lang}/word/{word}` with a query and a header parameter
+    fn lookup() -> Operation {
+        let spec = ApiSpec::from_json(&json!({
+            "paths": {
+                "/{lang}/word/{word}": {
+                    "parameters": [
+                        { "name": "lang", "in": "path", "required": true },
+                    ],
+                    "get": {
+                        "parameters": [
+                            { "name": "word", "in": "path", "required": true },
+                            { "name": "limit", "in": "query" },
+                            { "name": "sort by", "in": "query" },
+                            { "name": "x-request-id", "in": "header" },
+                        ],
+                    },
+                },
+            },
+        }));
+        spec.operations.into_iter().next().unwrap()
+    }
+
+    fn values(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn url_fills_path_templates() {
+        let url = lookup().url(
+            "https://api.example.com",
+            &values(&[("lang", "en"), ("word", "serendipity")]),
+        );
+        assert_eq!(url, "https://api.example.com/en/word/serendipity");
+    }
+
+    #[test]
+    fn url_encodes_path_values() {
+        for (word, encoded) in [
+            ("Straße", "Stra%C3%9Fe"),
+            ("café", "caf%C3%A9"),
+            ("a/b?c#d", "a%2Fb%3Fc%23d"),
+            ("{word}", "%7Bword%7D"),
+        ] {
+            let url = lookup().url("", &values(&[("lang", "en"), ("word", word)]));
+            assert_eq!(url, format!("/en/word/{}", encoded), "{:?}", word);
+        }
+    }
+
+    #[test]
+    fn url_trims_trailing_slashes_off_the_base() {
+        let values = values(&[("lang", "en"), ("word", "run")]);
+        for base in ["/api", "/api/", "/api//"] {
+            assert_eq!(
+                lookup().url(base, &values),
+                "/api/en/word/run",
+                "{:?}",
+                base
+            );
+        }
+    }
+
+    #[test]
+    fn url_appends_query_parameters_with_a_value() {
+        let url = lookup().url(
+            "/api",
+            &values(&[
+                ("lang", "en"),
+                ("word", "run"),
+                ("limit", "5"),
+                ("sort by", "ä&b"),
+                ("x-request-id", "abc"),
+                ("unknown", "ignored"),
+            ]),
+        );
+        assert_eq!(url, "/api/en/word/run?limit=5&sort%20by=%C3%A4%26b");
+    }
+
+    #[test]
+    fn url_skips_empty_query_values() {
+        let url = lookup().url(
+            "/api",
+            &values(&[("lang", "en"), ("word", "run"), ("limit", "")]),
+        );
+        assert_eq!(url, "/api/en/word/run");
+    }
+
+    #[test]
+    fn url_leaves_missing_path_values_empty() {
+        assert_eq!(lookup().url("/api", &[]), "/api//word/");
+    }
+
+    #[test]
+    fn headers_keeps_header_parameters_with_a_value() {
+        let headers = lookup().headers(&values(&[
+            ("x-request-id", "abc"),
+            ("limit", "5"),
+            ("x-other", "1"),
+        ]));
+        assert_eq!(headers, values(&[("x-request-id", "abc")]));
+        assert!(lookup()
+            .headers(&values(&[("x-request-id", "")]))
+            .is_empty());
+    }
+
+    #[test]
+    fn matches_searches_path_summary_description_and_tags() {
+        let spec = ApiSpec::from_json(&json!({
+            "paths": {
+                "/{lang}/{type}": {
+                    "get": {
+                        "summary": "Random word",
+                        "description": "Picks a word with its pronunciation",
+                        "tags": ["words"],
+                    },
+                },
+            },
+        }));
+        let operation = &spec.operations[0];
+
+        for needle in ["{type}", "RANDOM", "pronunciation", "Words", "  word ", ""] {
+            assert!(operation.matches(needle), "{:?}", needle);
+        }
+        assert!(!operation.matches("health"));
+    }
+}
@@ -0,0 +1,211 @@
+use any_spawner::Executor;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use futures::{stream, StreamExt};
+use leptos::prelude::*;
+use leptos_meta::ServerMetaContext;
+use sha2::{Digest, Sha256};
+
+use crate::app::App;
+use crate::model::api_urls;
+
+/// Globals the hydrating frontend reads. Nothing is serialized from the
+/// server, all resources on the page are browser-only.
+pub const HYDRATION_GLOBALS: &str =
+    "<script>__RESOLVED_RESOURCES=[];__SERIALIZED_ERRORS=[];__INCOMPLETE_CHUNKS=[];</script>";
+
+/// Where `leptos_meta` puts the page's `<head>` tags, and where the
+/// hydrating frontend looks for them. It's inserted here rather than kept in
+/// `index.html`, since Trunk's minifier drops comments.
+pub const HEAD_MARKER: &str = "<!--HEAD-->";
+
+/// Where the content of the first `<name>` tag of `html` starts
+fn after_open_tag(html: &str, name: &str) -> Option<usize> {
+    let open = format!("<{}", name);
+    let mut from = 0;
+    while let Some(start) = html[from..].find(&open).map(|start| from + start) {
+        let rest = &html[start + open.len()..];
+        if rest.starts_with(|c: char| c == '>' || c.is_ascii_whitespace()) {
+            return rest.find('>').map(|end| start + open.len() + end + 1);
+        }
+        from = start + open.len();
+    }
+    None
+}
+
+/// Trunk's `index.html` with the rendered `App` in its body, ready to be
+/// hydrated by a frontend built with the `hydrate` feature
+pub fn render_index(template: &str) -> std::io::Result<String> {
+    if template.contains(HYDRATION_GLOBALS) {
+        return Err(std::io::Error::other("index.html is already prerendered"));
+    }
+
+    _ = Executor::init_futures_executor();
+    let mut html = template.to_string();
+    let (meta, meta_tags) = ServerMetaContext::new();
+    let app = Owner::new().with(|| {
+        provide_context(meta);
+        view! { <App /> }.to_html()
+    });
+
+    let body = after_open_tag(&html, "body")
+        .ok_or_else(|| std::io::Error::other("index.html has no <body> tag"))?;
+    html.insert_str(body, &app);
+
+    let head_end = html
+        .find("</head>")
+        .ok_or_else(|| std::io::Error::other("index.html has no </head> tag"))?;
+    html.insert_str(head_end, HYDRATION_GLOBALS);
+
+    let head = after_open_tag(&html, "head")
+        .ok_or_else(|| std::io::Error::other("index.html has no <head> tag"))?;
+    html.insert_str(head, HEAD_MARKER);
+
+    // Title and meta tags registered while rendering go after the marker
+    let html = futures::executor::block_on(async {
+        meta_tags
+            .inject_meta_context(stream::iter([html]))
+            .await
+            .collect::<String>()
+            .await
+    });
+
+    Ok(html)
+}
+
+/// Stylesheets, fonts and flag images the page loads from elsewhere
+const STYLE_SOURCES: &[&str] = &["https://cdn.jsdelivr.net", "https://fonts.bunny.net"];
+const FONT_SOURCES: &[&str] = &["https://fonts.bunny.net"];
+const IMG_SOURCES: &[&str] = &["https://cdn.jsdelivr.net", "data:"];
+
+/// `scheme://host[:port]` of an absolute URL, `None` for same-origin paths
+fn origin(url: &str) -> Option<&str> {
+    let (_, rest) = url.split_once("://")?;
+    let end = rest
+        .find('/')
+        .map_or(url.len(), |end| url.len() - rest.len() + end);
+    Some(&url[..end])
+}
+
+/// `'sha256-…'` sources for every inline `<script>` in `html`, such as
+/// Trunk's loader and the hydration globals
+fn inline_script_hashes(html: &str) -> Vec<String> {
+    let mut hashes = Vec::new();
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<script") {
+        rest = &rest[start..];
+        let Some(open_end) = rest.find('>') else {
+            break;
+        };
+        let Some(close) = rest.find("</script>") else {
+            break;
+        };
+        let tag = &rest[..open_end];
+        if !tag.contains(" src=") {
+            let script = &rest[open_end + 1..close];
+            hashes.push(format!(
+                "'sha256-{}'",
+                STANDARD.encode(Sha256::digest(script))
+            ));
+        }
+        rest = &rest[close + "</script>".len()..];
+    }
+
+    hashes.sort();
+    hashes.dedup();
+    hashes
+}
+
+/// A Content-Security-Policy for the rendered `index.html`: scripts only
+/// from this origin or inline ones with a matching hash, and requests only
+/// to this origin and the backends in `API_URL`
+pub fn content_security_policy(index: &str) -> String {
+    let urls = api_urls();
+    let mut backends: Vec<&str> = urls.iter().filter_map(|url| origin(url)).collect();
+    backends.sort();
+    backends.dedup();
+
+    let mut scripts = vec!["'wasm-unsafe-eval'".to_string()];
+    scripts.extend(inline_script_hashes(index));
+
+    [
+        "default-src 'self'".to_string(),
+        directive("script-src", &scripts),
+        directive("style-src", STYLE_SOURCES),
+        directive("font-src", FONT_SOURCES),
+        directive("img-src", IMG_SOURCES),
+        directive("connect-src", &backends),
+        "object-src 'none'".to_string(),
+        "base-uri 'self'".to_string(),
+        "form-action 'self'".to_string(),
+        "frame-ancestors 'none'".to_string(),
+    ]
+    .join("; ")
+}
+
+/// A CSP directive allowing this origin and `sources`
+fn directive(name: &str, sources: &[impl AsRef<str>]) -> String {
+    let mut directive = format!("{} 'self'", name);
+    for source in sources {
+        directive.push(' ');
+        directive.push_str(source.as_ref());
+    }
+    directive
+}
+
+/// Netlify's `_headers` file with the security headers for every path
+pub fn render_headers(index: &str) -> String {
+    let headers = [
+        ("Content-Security-Policy", content_security_policy(index)),
+        (
+            "Strict-Transport-Security",
+            "max-age=31536000; includeSubDomains".to_string(),
+        ),
+        (
+            "Referrer-Policy",
+            "strict-origin-when-cross-origin".to_string(),
+        ),
+        ("X-Content-Type-Options", "nosniff".to_string()),
+    ];
+
+    let mut file = "/*\n".to_string();
+    for (name, value) in headers {
+        file.push_str(&format!("  {}: {}\n", name, value));
+    }
+    file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Trunk's output with `minify = "always"`: no comments, no whitespace
+    const MINIFIED: &str = "<!doctype html><html lang=en><head><meta charset=utf-8><link rel=stylesheet href=/style.css></head><body></body></html>";
+
+    #[test]
+    fn open_tag_ignores_longer_names() {
+        let html = "<header></header><head lang=en><body>";
+        assert_eq!(
+            after_open_tag(html, "head"),
+            Some(html.find("<body").unwrap())
+        );
+        assert_eq!(after_open_tag(html, "body"), Some(html.len()));
+        assert_eq!(after_open_tag(html, "html"), None);
+    }
+
+    #[test]
+    fn head_tags_go_right_after_the_head_tag() {
+        let html = render_index(MINIFIED).unwrap();
+        let marker = html.find(HEAD_MARKER).unwrap();
+        assert_eq!(marker, html.find("<head>").unwrap() + "<head>".len());
+        assert!(html.find("<title>").unwrap() > marker);
+        assert!(html.find("<title>").unwrap() < html.find("</head>").unwrap());
+        assert_eq!(html.matches(HEAD_MARKER).count(), 1);
+    }
+
+    #[test]
+    fn index_is_only_prerendered_once() {
+        let html = render_index(MINIFIED).unwrap();
+        assert!(render_index(&html).is_err());
+    }
+}
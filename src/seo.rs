@@ -0,0 +1,134 @@
+//! Search engine and social metadata for the landing page, and the
+//! `sitemap.xml` and `robots.txt` the prerender step writes next to it.
+
+use serde_json::{json, Value};
+
+use crate::api::{ApiRoute, DocsTool, HealthCheck};
+use crate::model::{api_urls, GrammaticalType, LanguageCode, REPO_URL};
+
+/// Where the landing page is published, `homepage` in Cargo.toml
+pub const SITE_URL: &str = env!("CARGO_PKG_HOMEPAGE");
+
+/// A route of the landing page, with what goes in its `<head>`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Page {
+    pub path: &'static str,
+    pub title: &'static str,
+    pub description: &'static str,
+}
+
+impl Page {
+    pub fn url(&self) -> String {
+        format!("{}{}", SITE_URL.trim_end_matches('/'), self.path)
+    }
+}
+
+pub const HOME: Page = Page {
+    path: "/",
+    title: "Random Word API",
+    description: "Live demo and documentation of the Random Word API, a simple dictionary \
+                  service built with Rust and Axum",
+};
+
+/// Every route listed in the sitemap
+pub const PAGES: &[Page] = &[HOME];
+
+/// The preferred backend as an absolute URL. Relative entries are published
+/// under the site itself.
+fn api_base() -> String {
+    let base = api_urls().into_iter().next().unwrap_or_default();
+    if base.starts_with('/') {
+        format!("{}{}", SITE_URL.trim_end_matches('/'), base)
+    } else {
+        base
+    }
+}
+
+fn entry_point(name: String, url: String) -> Value {
+    json!({
+        "@type": "Action",
+        "name": name,
+        "target": {
+            "@type": "EntryPoint",
+            "urlTemplate": url,
+            "httpMethod": "GET",
+            "contentType": "application/json",
+        },
+    })
+}
+
+/// schema.org `WebAPI` description of the backend and its endpoints
+pub fn json_ld() -> String {
+    let base = api_base();
+
+    let mut endpoints = Vec::new();
+    for lang in LanguageCode::all() {
+        for word_type in GrammaticalType::all() {
+            let route = ApiRoute::Random {
+                lang: *lang,
+                word_type: *word_type,
+            };
+            endpoints.push(entry_point(
+                format!("{} word ({})", word_type.name(), lang.name()),
+                route.url(&base),
+            ));
+        }
+        let lookup = ApiRoute::Lookup {
+            lang: *lang,
+            word: String::new(),
+        };
+        endpoints.push(entry_point(
+            format!("Word lookup ({})", lang.name()),
+            format!("{}{{word}}", lookup.url(&base)),
+        ));
+    }
+    for (name, check) in [
+        ("Liveness check", HealthCheck::Alive),
+        ("Readiness check", HealthCheck::Ready),
+    ] {
+        endpoints.push(entry_point(
+            name.to_string(),
+            ApiRoute::Health(check).url(&base),
+        ));
+    }
+    endpoints.push(entry_point(
+        "OpenAPI specification".to_string(),
+        ApiRoute::OpenApiSpec.url(&base),
+    ));
+
+    json!({
+        "@context": "https://schema.org",
+        "@type": "WebAPI",
+        "name": HOME.title,
+        "description": HOME.description,
+        "url": base,
+        "documentation": ApiRoute::Docs(DocsTool::SwaggerUi).url(&base),
+        "sameAs": REPO_URL,
+        "provider": {
+            "@type": "Person",
+            "name": env!("CARGO_PKG_AUTHORS"),
+        },
+        "potentialAction": endpoints,
+    })
+    .to_string()
+}
+
+pub fn sitemap() -> String {
+    let urls: String = PAGES
+        .iter()
+        .map(|page| format!("  <url>\n    <loc>{}</loc>\n  </url>\n", page.url()))
+        .collect();
+
+    format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\n{}</urlset>\n",
+        urls
+    )
+}
+
+pub fn robots_txt() -> String {
+    format!(
+        "User-agent: *\nAllow: /\n\nSitemap: {}/sitemap.xml\n",
+        SITE_URL.trim_end_matches('/')
+    )
+}
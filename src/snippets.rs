@@ -0,0 +1,57 @@
+/// Languages the Demo can generate ready-to-copy request code for
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SnippetLanguage {
+    Curl,
+    JavaScript,
+    Python,
+    Rust,
+}
+
+impl SnippetLanguage {
+    pub fn name(&self) -> &'static str {
+        match self {
+            SnippetLanguage::Curl => "curl",
+            SnippetLanguage::JavaScript => "JavaScript",
+            SnippetLanguage::Python => "Python",
+            SnippetLanguage::Rust => "Rust",
+        }
+    }
+
+    /// Code fetching a word from `url`, which must already be percent-encoded
+    pub fn snippet(&self, url: &str) -> String {
+        match self {
+            SnippetLanguage::Curl => format!("curl --compressed \"{url}\""),
+            SnippetLanguage::JavaScript => format!(
+                r#"const response = await fetch("{url}");
+const [word] = await response.json();
+console.log(word);"#
+            ),
+            SnippetLanguage::Python => format!(
+                r#"import requests
+
+response = requests.get("{url}")
+response.raise_for_status()
+word = response.json()[0]
+print(word)"#
+            ),
+            SnippetLanguage::Rust => format!(
+                r#"// reqwest = {{ version = "0.12", features = ["json"] }}
+let words: Vec<serde_json::Value> = reqwest::get("{url}")
+    .await?
+    .error_for_status()?
+    .json()
+    .await?;
+println!("{{:#?}}", words[0]);"#
+            ),
+        }
+    }
+
+    pub fn all() -> &'static [SnippetLanguage] {
+        &[
+            SnippetLanguage::Curl,
+            SnippetLanguage::JavaScript,
+            SnippetLanguage::Python,
+            SnippetLanguage::Rust,
+        ]
+    }
+}
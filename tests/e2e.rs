@@ -0,0 +1,312 @@
+//! End-to-end tests: the frontend built with Trunk, served next to a mock
+//! backend, driven through the demo by a headless browser.
+//!
+//! They need `trunk` on the `PATH` and a WebDriver server, so they're ignored
+//! by default. To run them:
+//!
+//! ```sh
+//! chromedriver --port=4444 &
+//! cargo test --test e2e -- --ignored
+//! ```
+//!
+//! Set `WEBDRIVER_URL` when the WebDriver listens elsewhere. The frontend is
+//! served by the same server as the mock backend, with `API_URL` pointing at
+//! the mock under `/api`.
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::get,
+    Json, Router,
+};
+use fantoccini::{error::CmdError, Client, ClientBuilder, Locator};
+use fe_leptos_landingpage::api::{API_VERSION_HEADER, SUPPORTED_API_VERSION};
+use serde_json::{json, Value};
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::process::Command;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::net::TcpListener;
+use tower_http::services::{ServeDir, ServeFile};
+
+/// How long a flow waits for the page to catch up
+const TIMEOUT: Duration = Duration::from_secs(10);
+/// How long the slow backend takes to answer
+const SLOW: Duration = Duration::from_secs(3);
+
+/// How the mock backend answers word requests
+#[derive(Clone)]
+enum Mode {
+    Word,
+    Status(StatusCode),
+    Body(&'static str),
+    Slow,
+}
+
+/// The mock backend, with the word requests it got
+struct Mock {
+    mode: Mutex<Mode>,
+    requests: Mutex<Vec<String>>,
+}
+
+impl Mock {
+    fn set_mode(&self, mode: Mode) {
+        *self.mode.lock().unwrap() = mode;
+    }
+
+    fn requests(&self) -> Vec<String> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+/// A word named after the route and the number of requests so far, so each
+/// answer is told apart from the previous one
+async fn random_word(
+    State(mock): State<Arc<Mock>>,
+    Path((lang, word_type)): Path<(String, String)>,
+) -> Response {
+    let count = {
+        let mut requests = mock.requests.lock().unwrap();
+        requests.push(format!("/{lang}/{word_type}"));
+        requests.len()
+    };
+
+    let mode = mock.mode.lock().unwrap().clone();
+    match mode {
+        Mode::Status(status) => return status.into_response(),
+        Mode::Body(body) => return body.into_response(),
+        Mode::Slow => tokio::time::sleep(SLOW).await,
+        Mode::Word => {}
+    }
+
+    let mut word: Value =
+        serde_json::from_str(include_str!("fixtures/word_full.json")).expect("word fixture");
+    word["word"] = json!(format!("{lang}-{word_type}-{count}"));
+    word["language"] = json!(lang);
+    word["grammatical_type"] = json!(word_type);
+    Json(json!([word])).into_response()
+}
+
+async fn alive() -> impl IntoResponse {
+    ([(API_VERSION_HEADER, SUPPORTED_API_VERSION)], "OK")
+}
+
+/// Served so the spec isn't taken for a word request, the API reference is
+/// left empty
+async fn openapi_spec() -> Json<Value> {
+    Json(json!({
+        "openapi": "3.1.0",
+        "info": { "title": "Mock Random Word API", "version": SUPPORTED_API_VERSION },
+        "paths": {},
+    }))
+}
+
+/// Builds the frontend once into the test's scratch directory, sending its
+/// requests to the mock backend at `addr`
+fn build_frontend(addr: SocketAddr) -> PathBuf {
+    let dist = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("e2e-dist");
+    let status = Command::new("trunk")
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .args(["build", "--features", "all-languages", "--dist"])
+        .arg(&dist)
+        .env("API_URL", format!("http://{addr}/api"))
+        .status()
+        .expect("trunk must be installed to run the end-to-end tests");
+    assert!(status.success(), "trunk build failed");
+    dist
+}
+
+/// Serves `dist` and the mock backend under `/api` on `listener`
+fn serve(listener: TcpListener, dist: PathBuf) -> Arc<Mock> {
+    let mock = Arc::new(Mock {
+        mode: Mutex::new(Mode::Word),
+        requests: Mutex::new(Vec::new()),
+    });
+
+    let api = Router::new()
+        .route("/health/alive", get(alive))
+        .route("/health/ready", get(|| async { "OK" }))
+        .route("/api-docs/openapi.json", get(openapi_spec))
+        .route("/{lang}/{word_type}", get(random_word))
+        .with_state(Arc::clone(&mock));
+    let app = Router::new()
+        .nest("/api", api)
+        .fallback_service(ServeDir::new(&dist).fallback(ServeFile::new(dist.join("index.html"))));
+
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+    mock
+}
+
+async fn browser() -> Client {
+    let url = std::env::var("WEBDRIVER_URL").unwrap_or_else(|_| "http://localhost:4444".into());
+    let capabilities = json!({
+        "goog:chromeOptions": { "args": ["--headless=new", "--no-sandbox"] },
+        "moz:firefoxOptions": { "args": ["-headless"] },
+    });
+    let capabilities = match capabilities {
+        Value::Object(map) => map,
+        _ => unreachable!(),
+    };
+
+    ClientBuilder::native()
+        .capabilities(capabilities)
+        .connect(&url)
+        .await
+        .unwrap_or_else(|e| panic!("no WebDriver at {url}: {e}"))
+}
+
+/// The demo card, so lookups don't match the rest of the page
+const DEMO: &str = "//div[@data-testid='demo']";
+
+/// The text of the demo's "word: ..." line, if a word is shown
+async fn shown_word(client: &Client) -> Result<Option<String>, CmdError> {
+    let xpath = format!("{DEMO}//p[starts-with(normalize-space(.), 'word:')]");
+    let found = client.find_all(Locator::XPath(&xpath)).await?;
+    match found.first() {
+        Some(element) => Ok(Some(element.text().await?)),
+        None => Ok(None),
+    }
+}
+
+/// Waits for a shown word other than `previous`, starting with `prefix`
+async fn wait_for_word(
+    client: &Client,
+    previous: Option<&str>,
+    prefix: &str,
+) -> Result<String, CmdError> {
+    let started = Instant::now();
+    loop {
+        let word = shown_word(client).await?;
+        if let Some(word) = word.filter(|word| Some(word.as_str()) != previous) {
+            assert!(
+                word.starts_with(&format!("word: {prefix}-")),
+                "expected a {prefix} word, got {word:?}"
+            );
+            return Ok(word);
+        }
+        assert!(started.elapsed() < TIMEOUT, "no new word after {TIMEOUT:?}");
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+}
+
+async fn wait_for_text(client: &Client, text: &str) -> Result<(), CmdError> {
+    let xpath = format!("//*[contains(normalize-space(.), {text:?})]");
+    client
+        .wait()
+        .at_most(TIMEOUT)
+        .for_element(Locator::XPath(&xpath))
+        .await?;
+    Ok(())
+}
+
+/// Opens the demo's `nth` dropdown (1 for languages, 2 for types) and clicks
+/// the entry matching `entry`
+async fn choose(client: &Client, nth: usize, entry: &str) -> Result<(), CmdError> {
+    let dropdown = format!("({DEMO}//div[contains(@class, 'dropdown')])[{nth}]");
+    client
+        .find(Locator::XPath(&format!("{dropdown}/div[@role='button']")))
+        .await?
+        .click()
+        .await?;
+    client
+        .wait()
+        .at_most(TIMEOUT)
+        .for_element(Locator::XPath(&format!("{dropdown}//ul//button[{entry}]")))
+        .await?
+        .click()
+        .await?;
+    Ok(())
+}
+
+async fn initial_load(client: &Client, url: &str, mock: &Mock) -> Result<String, CmdError> {
+    client.goto(url).await?;
+    let word = wait_for_word(client, None, "en-random").await?;
+    assert_eq!(
+        mock.requests().last().map(String::as_str),
+        Some("/en/random")
+    );
+    wait_for_text(client, "served by:").await?;
+    Ok(word)
+}
+
+async fn change_type(client: &Client, previous: &str, mock: &Mock) -> Result<String, CmdError> {
+    choose(client, 2, "normalize-space(.)='Verb'").await?;
+    let word = wait_for_word(client, Some(previous), "en-verb").await?;
+    assert_eq!(mock.requests().last().map(String::as_str), Some("/en/verb"));
+    wait_for_text(client, "New Verb").await?;
+    Ok(word)
+}
+
+async fn change_language(client: &Client, previous: &str, mock: &Mock) -> Result<String, CmdError> {
+    choose(client, 1, "span[@lang='de']").await?;
+    let word = wait_for_word(client, Some(previous), "de-verb").await?;
+    assert_eq!(mock.requests().last().map(String::as_str), Some("/de/verb"));
+    let xpath = format!("{DEMO}//div[@lang='de']/p[starts-with(normalize-space(.), 'word:')]");
+    client.find(Locator::XPath(&xpath)).await?;
+    Ok(word)
+}
+
+async fn new_word(client: &Client, previous: &str, mock: &Mock) -> Result<(), CmdError> {
+    let sent = mock.requests().len();
+    let xpath = format!("{DEMO}//button[starts-with(normalize-space(.), 'New')]");
+    client.find(Locator::XPath(&xpath)).await?.click().await?;
+    wait_for_word(client, Some(previous), "de-verb").await?;
+    assert_eq!(mock.requests().len(), sent + 1);
+    Ok(())
+}
+
+async fn backend_errors(client: &Client, url: &str, mock: &Mock) -> Result<(), CmdError> {
+    mock.set_mode(Mode::Status(StatusCode::INTERNAL_SERVER_ERROR));
+    client.goto(url).await?;
+    wait_for_text(client, "Error: Backend responded with status 500").await?;
+    assert_eq!(shown_word(client).await?, None);
+
+    mock.set_mode(Mode::Body("not json"));
+    client.goto(url).await?;
+    wait_for_text(client, "Error: Failed to parse JSON").await?;
+
+    mock.set_mode(Mode::Status(StatusCode::NOT_FOUND));
+    client.goto(url).await?;
+    wait_for_text(client, "Error: Backend responded with status 404").await?;
+    Ok(())
+}
+
+async fn slow_backend(client: &Client, url: &str, mock: &Mock) -> Result<(), CmdError> {
+    mock.set_mode(Mode::Slow);
+    let started = Instant::now();
+    client.goto(url).await?;
+    client
+        .wait()
+        .at_most(TIMEOUT)
+        .for_element(Locator::Css("[data-testid='demo'] span.loading"))
+        .await?;
+    assert_eq!(shown_word(client).await?, None);
+
+    wait_for_word(client, None, "en-random").await?;
+    assert!(started.elapsed() >= SLOW);
+    Ok(())
+}
+
+/// One build, server and browser for every flow, the Trunk build alone takes
+/// minutes. The flows run in order, each one starting where the last one left.
+#[tokio::test]
+#[ignore = "needs trunk and a WebDriver server, see the module docs"]
+async fn demo_flows() -> Result<(), CmdError> {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let dist = build_frontend(addr);
+    let mock = serve(listener, dist);
+    let url = format!("http://{addr}/");
+    let client = browser().await;
+
+    let word = initial_load(&client, &url, &mock).await?;
+    let word = change_type(&client, &word, &mock).await?;
+    let word = change_language(&client, &word, &mock).await?;
+    new_word(&client, &word, &mock).await?;
+    backend_errors(&client, &url, &mock).await?;
+    slow_backend(&client, &url, &mock).await?;
+
+    client.close().await
+}